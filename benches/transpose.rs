@@ -0,0 +1,78 @@
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use html_transpose::Table;
+use scraper::{Html, Selector};
+
+// 측정할 입력 테이블: 이름과 HTML 문서
+fn inputs() -> Vec<(&'static str, String)> {
+    vec![
+        ("small", generate(5, 5, false)),
+        ("wide", generate(10, 1000, false)),
+        ("tall", generate(5000, 5, false)),
+        ("merged", include_str!("../demo_input.html").to_string()),
+        ("merged_large", generate(500, 40, true)),
+    ]
+}
+
+// rows x cols 크기의 테이블. merged이면 demo_input.html처럼 rowspan/colspan이 섞인 셀을 반복해서 넣는다
+fn generate(rows: usize, cols: usize, merged: bool) -> String {
+    let mut covered = vec![vec![false; cols]; rows];
+    let mut html = String::from("<table border=\"1\">");
+    for r in 0..rows {
+        html.push_str("<tr>");
+        for c in 0..cols {
+            if covered[r][c] {
+                continue;
+            }
+            let (rowspan, colspan) = match (r % 4, c % 6) {
+                (0, 0) if merged => (1, 3),
+                (1, 0) if merged => (2, 1),
+                (2, 3) if merged => (2, 2),
+                _ => (1, 1),
+            };
+            let rowspan = rowspan.min(rows - r);
+            let colspan = (0..colspan).take_while(|i| c + i < cols && !covered[r][c + i]).count();
+            for row in covered.iter_mut().skip(r).take(rowspan) {
+                for slot in row.iter_mut().skip(c).take(colspan) {
+                    *slot = true;
+                }
+            }
+            let tag = if r == 0 { "th" } else { "td" };
+            html.push_str(&format!("<{tag} rowspan=\"{rowspan}\" colspan=\"{colspan}\" class=\"c{c}\">{r}-{c}</{tag}>"));
+        }
+        html.push_str("</tr>");
+    }
+    html.push_str("</table>");
+    html
+}
+
+// 단계별로 따로 측정: HTML 파싱, 테이블 모델 구성, 전치, HTML 출력
+fn stages(c: &mut Criterion) {
+    let table_selector = Selector::parse("table").unwrap();
+
+    for (name, html) in inputs() {
+        let mut group = c.benchmark_group(name);
+
+        group.bench_function(BenchmarkId::from_parameter("parse"), |b| {
+            b.iter(|| Html::parse_document(black_box(&html)))
+        });
+
+        let document = Html::parse_document(&html);
+        let element = document.select(&table_selector).next().unwrap();
+        group.bench_function(BenchmarkId::from_parameter("grid"), |b| {
+            b.iter(|| Table::from_html_element(black_box(element)).unwrap())
+        });
+
+        let table = Table::from_html_element(element).unwrap();
+        group.bench_function(BenchmarkId::from_parameter("transpose"), |b| b.iter(|| black_box(&table).transpose()));
+
+        let transposed = table.transpose();
+        group.bench_function(BenchmarkId::from_parameter("emit"), |b| b.iter(|| black_box(&transposed).to_html()));
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, stages);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+// ffi 기능을 켜면 C 헤더를 OUT_DIR에 만든다. 소스 트리는 건드리지 않는다
+// 저장소의 include/html_transpose.h와 같은지는 ffi 테스트가 확인한다
+fn main() {
+    #[cfg(feature = "ffi")]
+    {
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        cbindgen::generate(&crate_dir)
+            .expect("Failed to generate the C header")
+            .write_to_file(format!("{}/html_transpose.h", out_dir));
+    }
+}
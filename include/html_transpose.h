@@ -0,0 +1,61 @@
+/*
+ * html_transpose C API
+ *
+ * Every function returns HTML_TRANSPOSE_OK (0) and stores the result in *out,
+ * or returns HTML_TRANSPOSE_ERROR (-1) and stores an error message in *err.
+ * Free returned strings with html_transpose_string_free, not free().
+ */
+
+#ifndef HTML_TRANSPOSE_H
+#define HTML_TRANSPOSE_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define HTML_TRANSPOSE_OK 0
+
+#define HTML_TRANSPOSE_ERROR -1
+
+typedef struct HtmlTransposeOptions {
+  bool swap_scope;
+  bool infer_scope;
+  const char *id_prefix;
+  bool generate_headers;
+  bool swap_axis_styles;
+} HtmlTransposeOptions;
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct HtmlTransposeOptions html_transpose_options_default(void);
+
+int html_transpose_transpose(const char *html, char **out, char **err);
+
+int html_transpose_transpose_with_options(const char *html,
+                                          const struct HtmlTransposeOptions *options,
+                                          char **out,
+                                          char **err);
+
+int html_transpose_convert(const char *text,
+                           const char *from,
+                           const char *to,
+                           char **out,
+                           char **err);
+
+void html_transpose_string_free(char *s);
+
+const char *html_transpose_version(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HTML_TRANSPOSE_H */
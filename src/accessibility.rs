@@ -0,0 +1,289 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::table::Table;
+
+// headers/id 연결 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderIssue {
+    // 같은 id를 가진 셀이 여러 개
+    DuplicateId { id: String },
+    // headers가 테이블에 없는 id를 참조
+    UnknownHeader { row: usize, col: usize, id: String },
+    // headers가 헤더 셀(th)이 아닌 셀을 참조
+    NotAHeaderCell { row: usize, col: usize, id: String },
+}
+
+impl fmt::Display for HeaderIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderIssue::DuplicateId { id } => write!(f, "Duplicate cell id \"{}\"", id),
+            HeaderIssue::UnknownHeader { row, col, id } => {
+                write!(f, "Cell ({}, {}) refers to unknown header id \"{}\"", row, col, id)
+            }
+            HeaderIssue::NotAHeaderCell { row, col, id } => {
+                write!(f, "Cell ({}, {}) refers to \"{}\", which is not a header cell", row, col, id)
+            }
+        }
+    }
+}
+
+impl Table {
+    // headers 속성이 테이블 안의 헤더 셀 id를 올바르게 참조하는지 검사
+    pub fn header_issues(&self) -> Vec<HeaderIssue> {
+        let cells = self.cells();
+        let mut issues = Vec::new();
+
+        let mut ids: HashMap<&str, bool> = HashMap::new();
+        let mut reported: HashSet<&str> = HashSet::new();
+        for cell in &cells {
+            if let Some(id) = cell.attr("id")
+                && ids.insert(id, cell.is_header).is_some()
+                && reported.insert(id)
+            {
+                issues.push(HeaderIssue::DuplicateId { id: id.to_string() });
+            }
+        }
+
+        for cell in &cells {
+            for id in cell.attr("headers").unwrap_or("").split_whitespace() {
+                let (row, col, id_string) = (cell.row, cell.col, id.to_string());
+                match ids.get(id) {
+                    None => issues.push(HeaderIssue::UnknownHeader { row, col, id: id_string }),
+                    Some(false) => issues.push(HeaderIssue::NotAHeaderCell { row, col, id: id_string }),
+                    Some(true) => {}
+                }
+            }
+        }
+
+        issues
+    }
+
+    // 모든 셀 id와 headers 참조에 접두사를 붙인다
+    pub(crate) fn prefix_ids(&mut self, prefix: &str) {
+        let positions: Vec<(usize, usize)> = self
+            .cells()
+            .iter()
+            .filter(|cell| cell.attr("id").is_some() || cell.attr("headers").is_some())
+            .map(|cell| (cell.row, cell.col))
+            .collect();
+
+        for position in positions {
+            let attributes = self.cell_attributes_mut(position);
+            if let Some(id) = attributes.get_mut("id") {
+                *id = format!("{}{}", prefix, id);
+            }
+            if let Some(headers) = attributes.get_mut("headers") {
+                *headers = headers
+                    .split_whitespace()
+                    .map(|id| format!("{}{}", prefix, id))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+        }
+    }
+
+    // headers가 없는 데이터 셀에 같은 열 위쪽, 같은 행 왼쪽의 헤더 셀 id를 넣는다
+    // 참조할 헤더 셀에 id가 없으면 `{접두사}th-{행}-{열}` 형태로 만든다
+    pub(crate) fn generate_headers(&mut self, prefix: &str) {
+        let cells = self.cells();
+        let owners = self.owners(&cells);
+
+        let mut used_ids: HashSet<String> = cells
+            .iter()
+            .filter_map(|cell| cell.attr("id").map(str::to_string))
+            .collect();
+        let mut header_ids: HashMap<usize, String> = HashMap::new();
+        let mut new_ids: Vec<((usize, usize), String)> = Vec::new();
+        let mut new_headers: Vec<((usize, usize), String)> = Vec::new();
+
+        for cell in cells.iter().filter(|cell| !cell.is_header && cell.attr("headers").is_none()) {
+            // 열 머리글(위에서 아래로) 다음 행 머리글(왼쪽에서 오른쪽으로)
+            let mut found: Vec<usize> = Vec::new();
+            for row in &owners[..cell.row] {
+                found.extend(&row[cell.col..cell.col + cell.colspan]);
+            }
+            for row in &owners[cell.row..cell.row + cell.rowspan] {
+                found.extend(&row[..cell.col]);
+            }
+
+            let mut ids: Vec<String> = Vec::new();
+            for owner in found {
+                let Some(header) = cells.get(owner).filter(|header| header.is_header) else {
+                    continue;
+                };
+                let id = header_ids.entry(owner).or_insert_with(|| match header.attr("id") {
+                    Some(id) => id.to_string(),
+                    None => {
+                        let mut id = format!("{}th-{}-{}", prefix, header.row, header.col);
+                        while used_ids.contains(&id) {
+                            id.push('_');
+                        }
+                        used_ids.insert(id.clone());
+                        new_ids.push(((header.row, header.col), id.clone()));
+                        id
+                    }
+                });
+                if !ids.contains(id) {
+                    ids.push(id.clone());
+                }
+            }
+
+            if !ids.is_empty() {
+                new_headers.push(((cell.row, cell.col), ids.join(" ")));
+            }
+        }
+
+        for (position, id) in new_ids {
+            self.cell_attributes_mut(position).insert("id".to_string(), id);
+        }
+        for (position, headers) in new_headers {
+            self.cell_attributes_mut(position).insert("headers".to_string(), headers);
+        }
+    }
+
+    // 전치 후 헤더 셀의 scope를 반대 축으로 바꾼다
+    pub(crate) fn swap_scope(&mut self) {
+        let positions: Vec<(usize, usize)> = self
+            .cells()
+            .iter()
+            .filter(|cell| cell.attr("scope").is_some())
+            .map(|cell| (cell.row, cell.col))
+            .collect();
+
+        for position in positions {
+            if let Some(scope) = self.cell_attributes_mut(position).get_mut("scope")
+                && let Some(swapped) = swapped_scope(scope)
+            {
+                *scope = swapped.to_string();
+            }
+        }
+    }
+
+    // scope가 없는 헤더 셀에 scope 추가
+    // 셀이 걸친 행 전체가 헤더 셀이면 열 머리글(col), 걸친 열 전체가 헤더 셀이면 행 머리글(row).
+    // 여러 칸에 걸친 셀은 colgroup/rowgroup, 둘 다 해당하거나 둘 다 아니면 판단하지 않는다
+    pub(crate) fn infer_scope(&mut self) {
+        let cells = self.cells();
+        let owners = self.owners(&cells);
+        let is_header = |owner: usize| cells.get(owner).is_some_and(|cell| cell.is_header);
+
+        let mut inferred = Vec::new();
+        for cell in cells.iter().filter(|cell| cell.is_header && cell.attr("scope").is_none()) {
+            let header_row = owners[cell.row..cell.row + cell.rowspan]
+                .iter()
+                .all(|row| row.iter().all(|&owner| is_header(owner)));
+            let header_col = owners
+                .iter()
+                .all(|row| row[cell.col..cell.col + cell.colspan].iter().all(|&owner| is_header(owner)));
+
+            let scope = match (header_row, header_col) {
+                (true, false) if cell.colspan > 1 => "colgroup",
+                (true, false) => "col",
+                (false, true) if cell.rowspan > 1 => "rowgroup",
+                (false, true) => "row",
+                _ => continue,
+            };
+            inferred.push(((cell.row, cell.col), scope));
+        }
+
+        for (position, scope) in inferred {
+            self.cell_attributes_mut(position).insert("scope".to_string(), scope.to_string());
+        }
+    }
+}
+
+fn swapped_scope(scope: &str) -> Option<&'static str> {
+    match scope.trim().to_ascii_lowercase().as_str() {
+        "col" => Some("row"),
+        "row" => Some("col"),
+        "colgroup" => Some("rowgroup"),
+        "rowgroup" => Some("colgroup"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{HeaderIssue, Table, TransposeOptions};
+
+    // 전치하면 scope가 반대 축으로 바뀌어야 함
+    #[test]
+    fn test_scope_swapped_on_transpose() {
+        let table = Table::from_html(r#"<table>
+            <tr><th scope="col">Name</th><th scope="colgroup" colspan="2">Scores</th></tr>
+            <tr><td>Kim</td><td>1</td><td>2</td></tr>
+        </table>"#).unwrap();
+        let transposed = table.transpose();
+        assert_eq!(transposed.cell(0, 0).unwrap().attr("scope"), Some("row"));
+        assert_eq!(transposed.cell(1, 0).unwrap().attr("scope"), Some("rowgroup"));
+
+        let verbatim = TransposeOptions { swap_scope: false, ..TransposeOptions::default() };
+        let transposed = table.transpose_with(&verbatim);
+        assert_eq!(transposed.cell(0, 0).unwrap().attr("scope"), Some("col"));
+    }
+
+    // scope가 없는 헤더 셀은 전치된 위치로 scope를 추론
+    #[test]
+    fn test_infer_scope() {
+        let table = Table::from_html(r#"<table>
+            <tr><th colspan="2">Person</th><th scope="col">Age</th></tr>
+            <tr><th>Kim</th><td>A</td><td>30</td></tr>
+            <tr><th>Lee</th><td>B</td><td>25</td></tr>
+        </table>"#).unwrap();
+        let options = TransposeOptions { infer_scope: true, ..TransposeOptions::default() };
+        let transposed = table.transpose_with(&options);
+
+        // 첫 열 전체가 헤더 셀: Person(rowspan 2)은 rowgroup, Age는 원래 scope를 바꾼 row
+        assert_eq!(transposed.cell(0, 0).unwrap().attr("scope"), Some("rowgroup"));
+        assert_eq!(transposed.cell(2, 0).unwrap().attr("scope"), Some("row"));
+        // 첫 행의 Kim/Lee는 행 전체가 헤더 셀이므로 col, 데이터 셀에는 추가하지 않음
+        assert_eq!(transposed.cell(0, 1).unwrap().attr("scope"), Some("col"));
+        assert!(transposed.cell(1, 1).unwrap().attr("scope").is_none());
+    }
+
+    // 중복 id, 없는 id 참조, 데이터 셀 참조를 찾아야 함
+    #[test]
+    fn test_header_issues() {
+        let table = Table::from_html(r#"<table>
+            <tr><th id="h1">A</th><th id="h1">B</th><td id="d">C</td></tr>
+            <tr><td headers="h1 missing">1</td><td headers="d">2</td><td>3</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.header_issues(), vec![
+            HeaderIssue::DuplicateId { id: "h1".to_string() },
+            HeaderIssue::UnknownHeader { row: 1, col: 0, id: "missing".to_string() },
+            HeaderIssue::NotAHeaderCell { row: 1, col: 1, id: "d".to_string() },
+        ]);
+    }
+
+    // 접두사는 id와 headers 참조에 함께 붙어서 연결이 유지되어야 함
+    #[test]
+    fn test_id_prefix_keeps_references() {
+        let table = Table::from_html(r#"<table>
+            <tr><th id="name">Name</th><td headers="name">Kim</td></tr>
+        </table>"#).unwrap();
+        let options = TransposeOptions { id_prefix: Some("t1-".to_string()), ..TransposeOptions::default() };
+        let transposed = table.transpose_with(&options);
+        assert_eq!(transposed.cell(0, 0).unwrap().attr("id"), Some("t1-name"));
+        assert_eq!(transposed.cell(1, 0).unwrap().attr("headers"), Some("t1-name"));
+        assert!(transposed.header_issues().is_empty());
+    }
+
+    // 전치된 헤더 위치로 headers 생성 (열 머리글 다음 행 머리글, 기존 id 재사용)
+    #[test]
+    fn test_generate_headers() {
+        let table = Table::from_html(r#"<table>
+            <tr><td></td><th id="kim">Kim</th><th>Lee</th></tr>
+            <tr><th>Age</th><td>30</td><td>25</td></tr>
+        </table>"#).unwrap();
+        let options = TransposeOptions { generate_headers: true, ..TransposeOptions::default() };
+        let transposed = table.transpose_with(&options);
+
+        assert_eq!(transposed.cell(0, 1).unwrap().attr("id"), Some("th-0-1"));
+        assert_eq!(transposed.cell(2, 0).unwrap().attr("id"), Some("th-2-0"));
+        assert_eq!(transposed.cell(1, 1).unwrap().attr("headers"), Some("th-0-1 kim"));
+        assert_eq!(transposed.cell(2, 1).unwrap().attr("headers"), Some("th-0-1 th-2-0"));
+        assert!(transposed.cell(0, 0).unwrap().attr("headers").is_none());
+        assert!(transposed.header_issues().is_empty());
+    }
+}
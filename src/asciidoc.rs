@@ -0,0 +1,118 @@
+use std::fmt;
+
+use crate::table::{Table, single_line};
+
+// AsciiDoc 테이블 렌더링
+// 병합 셀은 span 지정자로 표현: colspan은 `2+|`, rowspan은 `.2+|`, 둘 다면 `2.3+|`
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    let rows = table.row_count();
+    let cols = table.col_count();
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    // 첫 행이 모두 헤더 셀이고 아래로 병합되지 않았으면 헤더 행으로 지정
+    let header_row = rows > 1
+        && (0..cols).all(|c| {
+            table.cell(0, c).is_some_and(|cell| cell.is_header && cell.rowspan == 1)
+                || table.is_covered(0, c)
+        });
+
+    write!(out, "[cols=\"{}*\"", cols)?;
+    if header_row {
+        out.write_str(",options=\"header\"")?;
+    }
+    out.write_str("]\n|===\n")?;
+
+    for r in 0..rows {
+        let mut line: Vec<String> = Vec::new();
+        for c in 0..cols {
+            if let Some(cell) = table.cell(r, c) {
+                let mut spec = String::new();
+                if cell.colspan > 1 {
+                    spec.push_str(&cell.colspan.to_string());
+                }
+                if cell.rowspan > 1 {
+                    spec.push_str(&format!(".{}", cell.rowspan));
+                }
+                if !spec.is_empty() {
+                    spec.push('+');
+                }
+                // 헤더 행이 아닌 곳의 헤더 셀은 셀 스타일 h로 표시
+                if cell.is_header && !(header_row && r == 0) {
+                    spec.push('h');
+                }
+                line.push(format!("{}|{}", spec, escape_asciidoc(&single_line(cell.content))));
+            } else if !table.is_covered(r, c) {
+                line.push("|".to_string());
+            }
+        }
+        writeln!(out, "{}", line.join(" "))?;
+    }
+
+    out.write_str("|===\n")
+}
+
+// 셀 구분자와 겹치지 않도록 | 이스케이프
+fn escape_asciidoc(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 헤더 행이 있는 단순 테이블 테스트
+    #[test]
+    fn test_simple_table_with_header() {
+        let table = Table::from_html(r#"<table>
+            <tr><th>Name</th><th>Age</th></tr>
+            <tr><td>Kim</td><td>30</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_asciidoc(), concat!(
+            "[cols=\"2*\",options=\"header\"]\n",
+            "|===\n",
+            "|Name |Age\n",
+            "|Kim |30\n",
+            "|===\n",
+        ));
+    }
+
+    // 병합 셀 span 지정자 테스트
+    #[test]
+    fn test_merged_cells() {
+        let table = Table::from_html(r#"<table>
+            <tr><td rowspan="2" colspan="2">A</td><td>B</td></tr>
+            <tr><td>C</td></tr>
+            <tr><td rowspan="2">D</td><td colspan="2">E</td></tr>
+            <tr><td>F</td><td>G</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_asciidoc(), concat!(
+            "[cols=\"3*\"]\n",
+            "|===\n",
+            "2.2+|A |B\n",
+            "|C\n",
+            ".2+|D 2+|E\n",
+            "|F |G\n",
+            "|===\n",
+        ));
+    }
+
+    // 첫 행이 아닌 헤더 셀은 h 스타일, 빈 위치는 빈 셀, | 는 이스케이프
+    #[test]
+    fn test_header_style_and_escape() {
+        let table = Table::from_html(r#"<table>
+            <tr><th>a|b</th><td>1</td></tr>
+            <tr><th colspan="2">Total</th></tr>
+            <tr><td>x</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_asciidoc(), concat!(
+            "[cols=\"2*\"]\n",
+            "|===\n",
+            "h|a\\|b |1\n",
+            "2+h|Total\n",
+            "|x |\n",
+            "|===\n",
+        ));
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::css::{Declaration, parse_style, serialize_style, split_values};
+use crate::table::Table;
+
+// 가로 <-> 세로로 짝을 이루는 CSS 속성
+const PROPERTY_PAIRS: &[(&str, &str)] = &[
+    ("width", "height"),
+    ("min-width", "min-height"),
+    ("max-width", "max-height"),
+    ("overflow-x", "overflow-y"),
+    ("text-align", "vertical-align"),
+    ("border-left", "border-top"),
+    ("border-right", "border-bottom"),
+    ("border-left-width", "border-top-width"),
+    ("border-right-width", "border-bottom-width"),
+    ("border-left-style", "border-top-style"),
+    ("border-right-style", "border-bottom-style"),
+    ("border-left-color", "border-top-color"),
+    ("border-right-color", "border-bottom-color"),
+    ("border-top-right-radius", "border-bottom-left-radius"),
+    ("padding-left", "padding-top"),
+    ("padding-right", "padding-bottom"),
+    ("margin-left", "margin-top"),
+    ("margin-right", "margin-bottom"),
+    ("scroll-padding-left", "scroll-padding-top"),
+    ("scroll-padding-right", "scroll-padding-bottom"),
+    ("left", "top"),
+    ("right", "bottom"),
+];
+
+// 상/우/하/좌 값을 한 번에 지정하는 축약 속성
+const BOX_SHORTHANDS: &[&str] = &["padding", "margin", "border-width", "border-style", "border-color"];
+
+// 방향에 따라 뜻이 달라지는 HTML 속성
+const AXIS_ATTRIBUTES: &[&str] = &["width", "height", "align", "valign"];
+
+impl Table {
+    // 전치 후 셀의 방향 관련 속성(width/height, align/valign)과 인라인 스타일을 반대 축으로 바꾼다
+    // 테이블 자체의 속성은 페이지 안에서 테이블 상자를 설명하므로 바꾸지 않는다
+    // 바꿀 속성이 있는 셀만 속성 맵을 복사하고, 나머지 셀은 원래 테이블과 맵을 계속 공유한다
+    pub(crate) fn swap_axis_styles(&mut self) {
+        for cell in &mut self.cells {
+            if let Some(attributes) = &mut cell.attributes
+                && has_axis_attributes(attributes)
+            {
+                swap_axis_attributes(Arc::make_mut(attributes));
+            }
+        }
+    }
+}
+
+fn has_axis_attributes(attributes: &HashMap<String, String>) -> bool {
+    AXIS_ATTRIBUTES.iter().any(|name| attributes.contains_key(*name))
+        || attributes.get("style").is_some_and(|style| {
+            parse_style(style).iter().any(|declaration| is_axis_property(&declaration.property))
+        })
+}
+
+fn is_axis_property(property: &str) -> bool {
+    let lower = property.to_ascii_lowercase();
+    lower == "overflow"
+        || BOX_SHORTHANDS.contains(&lower.as_str())
+        || PROPERTY_PAIRS.iter().any(|&(horizontal, vertical)| lower == horizontal || lower == vertical)
+}
+
+fn swap_axis_attributes(attributes: &mut HashMap<String, String>) {
+    let width = attributes.remove("width");
+    let height = attributes.remove("height");
+    if let Some(height) = height {
+        attributes.insert("width".to_string(), height);
+    }
+    if let Some(width) = width {
+        attributes.insert("height".to_string(), width);
+    }
+
+    // align은 가로 정렬, valign은 세로 정렬: 대응하는 값이 있을 때만 옮긴다
+    let align = attributes.remove("align");
+    let valign = attributes.remove("valign");
+    let new_align = valign.as_deref().and_then(swapped_alignment);
+    let new_valign = align.as_deref().and_then(swapped_alignment);
+    match (new_align, align) {
+        (Some(value), _) => {
+            attributes.insert("align".to_string(), value.to_string());
+        }
+        (None, Some(align)) if new_valign.is_none() => {
+            attributes.insert("align".to_string(), align);
+        }
+        _ => {}
+    }
+    match (new_valign, valign) {
+        (Some(value), _) => {
+            attributes.insert("valign".to_string(), value.to_string());
+        }
+        (None, Some(valign)) if new_align.is_none() => {
+            attributes.insert("valign".to_string(), valign);
+        }
+        _ => {}
+    }
+
+    if let Some(style) = attributes.get_mut("style") {
+        *style = swap_style(style);
+    }
+}
+
+// 인라인 스타일의 각 선언을 반대 축으로 바꾼다. 모르는 속성과 !important는 그대로 유지
+fn swap_style(style: &str) -> String {
+    let declarations: Vec<Declaration> = parse_style(style).into_iter().map(swap_declaration).collect();
+    serialize_style(&declarations)
+}
+
+fn swap_declaration(declaration: Declaration) -> Declaration {
+    let lower = declaration.property.to_ascii_lowercase();
+
+    if BOX_SHORTHANDS.contains(&lower.as_str()) {
+        let value = swap_box_values(&declaration.value);
+        return Declaration { value, ..declaration };
+    }
+    if lower == "overflow" {
+        let value = split_values(&declaration.value).into_iter().rev().collect::<Vec<_>>().join(" ");
+        return Declaration { value, ..declaration };
+    }
+
+    let swapped = PROPERTY_PAIRS.iter().find_map(|&(horizontal, vertical)| {
+        if lower == horizontal {
+            Some(vertical)
+        } else if lower == vertical {
+            Some(horizontal)
+        } else {
+            None
+        }
+    });
+    match swapped {
+        Some(swapped @ ("text-align" | "vertical-align")) => match swapped_alignment(&declaration.value) {
+            Some(value) => Declaration {
+                property: swapped.to_string(),
+                value: value.to_string(),
+                ..declaration
+            },
+            None => declaration,
+        },
+        Some(swapped) => Declaration {
+            property: swapped.to_string(),
+            ..declaration
+        },
+        None => declaration,
+    }
+}
+
+// 상 우 하 좌 값을 전치: 상 <- 좌, 우 <- 하, 하 <- 우, 좌 <- 상
+fn swap_box_values(value: &str) -> String {
+    let values = split_values(value);
+    let (top, right, bottom, left) = match values.as_slice() {
+        [vertical, horizontal] => return format!("{} {}", horizontal, vertical),
+        [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+        [top, right, bottom, left] => (top, right, bottom, left),
+        _ => return value.to_string(),
+    };
+    format!("{} {} {} {}", left, bottom, right, top)
+}
+
+// 가로 정렬 값 <-> 세로 정렬 값
+fn swapped_alignment(value: &str) -> Option<&'static str> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "left" => Some("top"),
+        "center" => Some("middle"),
+        "right" => Some("bottom"),
+        "top" => Some("left"),
+        "middle" => Some("center"),
+        "bottom" => Some("right"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{Table, TransposeOptions};
+
+    fn swapped(cell_html: &str) -> Table {
+        let html = format!("<table><tr>{}</tr></table>", cell_html);
+        let options = TransposeOptions { swap_axis_styles: true, ..TransposeOptions::default() };
+        Table::from_html(&html).unwrap().transpose_with(&options)
+    }
+
+    // width/height, align/valign 속성 교환
+    #[test]
+    fn test_swap_attributes() {
+        let table = swapped(r#"<td width="120" align="right" valign="top">A</td>"#);
+        let cell = table.cell(0, 0).unwrap();
+        assert_eq!(cell.attr("height"), Some("120"));
+        assert_eq!(cell.attr("width"), None);
+        assert_eq!(cell.attr("align"), Some("left"));
+        assert_eq!(cell.attr("valign"), Some("bottom"));
+
+        // 대응하는 값이 없으면 원래 속성에 남는다
+        let table = swapped(r#"<td align="justify">A</td>"#);
+        assert_eq!(table.cell(0, 0).unwrap().attr("align"), Some("justify"));
+    }
+
+    // 인라인 스타일의 방향 속성과 축약 속성 교환
+    #[test]
+    fn test_swap_style() {
+        let table = swapped(r#"<td style="width: 10px; border-left: 1px solid; padding: 1px 2px 3px 4px; text-align: center; color: red">A</td>"#);
+        assert_eq!(table.cell(0, 0).unwrap().attr("style"), Some(
+            "height: 10px; border-top: 1px solid; padding: 4px 3px 2px 1px; vertical-align: middle; color: red"
+        ));
+
+        let table = swapped(r#"<td style="margin: 1px 2px">A</td>"#);
+        assert_eq!(table.cell(0, 0).unwrap().attr("style"), Some("margin: 2px 1px"));
+    }
+
+    // !important, 모르는 속성, 괄호 값, 축약 속성 안의 calc()를 잃지 않아야 함
+    #[test]
+    fn test_swap_style_keeps_unknown_and_important() {
+        let table = swapped(r#"<td style="padding-left: 2px !important; min-width: calc(1em + 2px); border-right: 1px solid red; padding: calc(1px + 1px) 0 3px; overflow: hidden auto; -x-foo: bar; background: url(a;b.png)">A</td>"#);
+        assert_eq!(table.cell(0, 0).unwrap().attr("style"), Some(concat!(
+            "padding-top: 2px !important; min-height: calc(1em + 2px); border-bottom: 1px solid red; ",
+            "padding: 0 3px 0 calc(1px + 1px); overflow: auto hidden; -x-foo: bar; background: url(a;b.png)",
+        )));
+    }
+
+    // 방향 관련 속성이 없는 셀은 속성 맵을 새로 만들거나 복사하지 않는다
+    #[test]
+    fn test_swap_keeps_shared_attributes() {
+        let table = Table::from_html(r#"<table><tr><td class="a" style="color: red">A</td><td>B</td><td width="1">C</td></tr></table>"#).unwrap();
+        let options = TransposeOptions { swap_axis_styles: true, ..TransposeOptions::default() };
+        let transposed = table.transpose_with(&options);
+        let attributes = |table: &Table, index: usize| table.cells[index].attributes.clone();
+        assert!(Arc::ptr_eq(&attributes(&table, 0).unwrap(), &attributes(&transposed, 0).unwrap()));
+        assert!(attributes(&transposed, 1).is_none());
+        assert!(!Arc::ptr_eq(&attributes(&table, 2).unwrap(), &attributes(&transposed, 2).unwrap()));
+        assert_eq!(transposed.cell(2, 0).unwrap().attr("height"), Some("1"));
+    }
+
+    // 기본 옵션에서는 속성을 그대로 둔다
+    #[test]
+    fn test_default_keeps_styles() {
+        let table = Table::from_html(r#"<table><tr><td width="120">A</td></tr></table>"#).unwrap();
+        assert_eq!(table.transpose().cell(0, 0).unwrap().attr("width"), Some("120"));
+    }
+}
@@ -0,0 +1,659 @@
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+
+use html_transpose::{Format, Rotation, Table, TransposeOptions, rewrite_tables};
+
+mod batch;
+mod mdbook;
+mod messages;
+mod serve;
+mod watch;
+
+use messages::{Lang, Message};
+
+const SUBCOMMANDS: &[&str] = &["transpose", "rotate", "convert", "validate", "inspect", "watch", "serve", "mdbook", "help"];
+
+// 도움말 문구는 messages::localize가 선택한 언어로 붙인다
+#[derive(Parser)]
+#[command(name = "html_transpose", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[arg(long, global = true, value_enum, value_name = "LANG")]
+    lang: Option<Lang>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    Transpose {
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+        #[command(flatten)]
+        options: TransposeArgs,
+    },
+    Rotate {
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+        #[arg(long, value_enum, default_value_t = Direction::Cw)]
+        direction: Direction,
+    },
+    Convert {
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    Validate {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    Inspect {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    Watch {
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        output: OutputArgs,
+        #[command(flatten)]
+        options: TransposeArgs,
+        #[arg(long, value_name = "MS", default_value_t = 200)]
+        debounce: u64,
+    },
+    Serve {
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:8080")]
+        listen: String,
+        #[command(flatten)]
+        options: TransposeArgs,
+        #[arg(short, long)]
+        quiet: bool,
+    },
+    Mdbook {
+        #[command(subcommand)]
+        command: Option<MdbookCommand>,
+        #[command(flatten)]
+        options: TransposeArgs,
+    },
+}
+
+// mdBook이 전처리기를 부를 때 쓰는 하위 명령
+#[derive(Subcommand)]
+enum MdbookCommand {
+    Supports { renderer: String },
+}
+
+#[derive(Args)]
+struct InputArgs {
+    #[arg(value_name = "INPUT")]
+    paths: Vec<PathBuf>,
+    #[arg(long, value_name = "FORMAT")]
+    from: Option<Format>,
+    #[arg(long, value_name = "SELECTOR")]
+    selector: Option<String>,
+    #[arg(long, conflicts_with = "selector")]
+    stream: bool,
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(Args)]
+struct OutputArgs {
+    #[arg(long, value_name = "FORMAT")]
+    to: Option<Format>,
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["output", "in_place"])]
+    output_dir: Option<PathBuf>,
+    #[arg(long, conflicts_with = "output")]
+    in_place: bool,
+    #[arg(
+        long,
+        value_name = "SUFFIX",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = ".bak",
+        requires = "in_place"
+    )]
+    backup: Option<String>,
+}
+
+#[derive(Args)]
+struct TransposeArgs {
+    #[arg(long)]
+    infer_scope: bool,
+    #[arg(long)]
+    no_swap_scope: bool,
+    #[arg(long)]
+    swap_axis_styles: bool,
+    #[arg(long)]
+    generate_headers: bool,
+    #[arg(long, value_name = "PREFIX")]
+    id_prefix: Option<String>,
+}
+
+impl TransposeArgs {
+    fn options(&self) -> TransposeOptions {
+        TransposeOptions {
+            swap_scope: !self.no_swap_scope,
+            infer_scope: self.infer_scope,
+            id_prefix: self.id_prefix.clone(),
+            generate_headers: self.generate_headers,
+            swap_axis_styles: self.swap_axis_styles,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Direction {
+    Cw,
+    Ccw,
+    #[value(name = "180")]
+    HalfTurn,
+}
+
+// 오류 종류별 종료 코드: 1 검사 실패, 2 잘못된 사용법, 3 입출력 오류, 4 파싱/포맷 오류
+#[derive(Debug)]
+enum CliError {
+    Usage(String),
+    Io(String),
+    Format(String),
+}
+
+impl CliError {
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Usage(_) => 2,
+            CliError::Io(_) => 3,
+            CliError::Format(_) => 4,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) | CliError::Io(message) | CliError::Format(message) => f.write_str(message),
+        }
+    }
+}
+
+pub fn main() -> ExitCode {
+    let args = legacy_args(std::env::args_os().collect());
+    let lang = Lang::detect(&args);
+    messages::set_lang(lang);
+    let matches = messages::localize(Cli::command(), lang).get_matches_from(args);
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    match run(cli.command) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("{}: {}", Message::Error, error);
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+// 예전 사용법 `<입력파일> [출력파일]` 을 transpose 하위 명령으로 바꾼다
+// 출력 파일이 없으면 예전처럼 입력 파일명에 .transposed.html 을 붙여 저장한다
+fn legacy_args(mut args: Vec<OsString>) -> Vec<OsString> {
+    let Some(first) = args.get(1).and_then(|arg| arg.to_str()) else {
+        return args;
+    };
+    if SUBCOMMANDS.contains(&first) || (first.starts_with('-') && first != "-") {
+        return args;
+    }
+
+    let input = args[1].clone();
+    // 두 번째 인자가 옵션(-q 등)이면 출력 파일이 아니다. - 는 표준 출력
+    let explicit_output = args.get(2).filter(|arg| *arg == "-" || !arg.to_string_lossy().starts_with('-')).cloned();
+    let consumed = if explicit_output.is_some() { 2 } else { 1 };
+    let output = match explicit_output {
+        Some(output) => Some(output),
+        None if input != "-" => {
+            let name = input.to_string_lossy();
+            let stem = name.trim_end_matches(".html").trim_end_matches(".htm");
+            Some(OsString::from(format!("{}.transposed.html", stem)))
+        }
+        None => None,
+    };
+
+    let mut converted = vec![args.remove(0), "transpose".into(), input];
+    if let Some(output) = output {
+        converted.extend(["--output".into(), output]);
+    }
+    converted.extend(args.into_iter().skip(consumed));
+    converted
+}
+
+fn run(command: Command) -> Result<ExitCode, CliError> {
+    match command {
+        Command::Transpose { input, output, options } => {
+            let options = options.options();
+            transform(&input, &output, "transposed", |table| table.transpose_with(&options))
+        }
+        Command::Rotate { input, output, direction } => {
+            let rotation = match direction {
+                Direction::Cw => Rotation::Clockwise,
+                Direction::Ccw => Rotation::CounterClockwise,
+                Direction::HalfTurn => Rotation::HalfTurn,
+            };
+            transform(&input, &output, "rotated", |table| table.rotate(rotation))
+        }
+        Command::Convert { input, output } => {
+            if output.to.is_none() {
+                return Err(CliError::Usage(Message::ConvertNeedsTo.to_string()));
+            }
+            transform(&input, &output, "converted", Table::clone)
+        }
+        Command::Validate { input } => report(&input, |table, _| {
+            let issues: Vec<String> = table.header_issues().iter().map(ToString::to_string).collect();
+            let ok = issues.is_empty();
+            (issues, ok)
+        }),
+        Command::Inspect { input } => report(&input, |table, from| {
+            let cells = table.cells();
+            let merged = cells.iter().filter(|cell| cell.rowspan > 1 || cell.colspan > 1).count();
+            let headers = cells.iter().filter(|cell| cell.is_header).count();
+            let mut lines = vec![
+                Message::InspectFormat(from).to_string(),
+                Message::InspectRows(table.row_count()).to_string(),
+                Message::InspectCols(table.col_count()).to_string(),
+                Message::InspectCells { cells: cells.len(), merged, headers }.to_string(),
+            ];
+            let mut attributes: Vec<_> = table.table_attributes().collect();
+            attributes.sort();
+            lines.extend(attributes.iter().map(|(name, value)| Message::InspectAttribute(name, value).to_string()));
+            (lines, true)
+        }),
+        Command::Watch { input, output, options, debounce } => {
+            // 결과를 입력 파일에 쓰면 그 변경이 다시 실행을 부르므로 입력 파일 하나와 별도의 출력만 허용한다
+            let path = match input.paths.as_slice() {
+                [path] if path != Path::new("-") && !batch::is_batch(&input.paths) => path,
+                _ => return Err(CliError::Usage(Message::WatchNeedsFile.to_string())),
+            };
+            let same_file = output
+                .output
+                .as_ref()
+                .is_some_and(|target| target.canonicalize().ok() == path.canonicalize().ok());
+            if output.in_place || output.output_dir.is_some() || same_file {
+                return Err(CliError::Usage(Message::WatchNeedsOutput.to_string()));
+            }
+
+            let options = options.options();
+            let apply = |table: &Table| table.transpose_with(&options);
+            watch::watch(path, Duration::from_millis(debounce), input.quiet, || {
+                if let Err(error) = transform_single(Some(path), &input, &output, &apply) {
+                    eprintln!("{}: {}", Message::Error, error);
+                }
+            })?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Serve { listen, options, quiet } => {
+            serve::serve(&listen, &options.options(), quiet)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Mdbook { command: Some(MdbookCommand::Supports { renderer }), .. } => {
+            Ok(if mdbook::supports(&renderer) { ExitCode::SUCCESS } else { ExitCode::from(1) })
+        }
+        Command::Mdbook { command: None, options } => {
+            mdbook::preprocess(&options.options())?;
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+// 테이블을 바꿔 저장하는 하위 명령 공통 처리
+fn transform(
+    input: &InputArgs,
+    output: &OutputArgs,
+    suffix: &str,
+    apply: impl Fn(&Table) -> Table,
+) -> Result<ExitCode, CliError> {
+    if !batch::is_batch(&input.paths) && output.output_dir.is_none() {
+        transform_single(single_path(&input.paths), input, output, &apply)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if output.output.is_some() {
+        return Err(CliError::Usage(Message::BatchNeedsOutputDir.to_string()));
+    }
+    let sources = batch::expand(&input.paths, input.from, Some(suffix))?;
+    let mut summary = Summary::default();
+    for source in &sources {
+        let result = if output.in_place {
+            transform_in_place(&source.path, input, output, &apply)
+        } else {
+            read_table(Some(&source.path), input).and_then(|(table, from)| {
+                let to = output.to.unwrap_or(from);
+                let target = batch::output_path(source, output.output_dir.as_deref(), suffix, to);
+                if let Some(parent) = target.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| CliError::Io(format!("{}: {}", Message::CreateDir(parent), e)))?;
+                }
+                write_table(Some(&target), &apply(&table), to, input.quiet)
+            })
+        };
+        summary.record(&source.path, result);
+    }
+    Ok(summary.finish(input.quiet))
+}
+
+// 입력 하나를 바꿔 -o 파일이나 stdout, 또는 --in-place로 입력 파일에 쓴다
+fn transform_single(
+    path: Option<&Path>,
+    input: &InputArgs,
+    output: &OutputArgs,
+    apply: &impl Fn(&Table) -> Table,
+) -> Result<(), CliError> {
+    if output.in_place {
+        let path = path.ok_or_else(|| CliError::Usage(Message::InPlaceNeedsFile.to_string()))?;
+        return transform_in_place(path, input, output, apply);
+    }
+    let (table, from) = read_table(path, input)?;
+    let target = output.output.as_deref().filter(|path| *path != Path::new("-"));
+    write_table(target, &apply(&table), output.to.unwrap_or(from), input.quiet)
+}
+
+// 입력 파일을 결과로 덮어쓴다
+// html 문서는 선택된 테이블만 바꾸고 나머지 내용은 그대로 두며, 다른 포맷은 파일 전체를 결과 테이블로 바꾼다
+fn transform_in_place(
+    path: &Path,
+    input: &InputArgs,
+    output: &OutputArgs,
+    apply: &impl Fn(&Table) -> Table,
+) -> Result<(), CliError> {
+    if input.stream {
+        return Err(CliError::Usage(Message::StreamInPlace.to_string()));
+    }
+    let rendered = if input_format(Some(path), input) == Format::Html {
+        if output.to.is_some_and(|to| to != Format::Html) {
+            return Err(CliError::Usage(Message::InPlaceFormatChange.to_string()));
+        }
+        let text = read_text(Some(path))?;
+        let selector = input.selector.as_deref().unwrap_or("table");
+        rewrite_tables(&text, selector, apply).map_err(CliError::Format)?
+    } else {
+        let (table, from) = read_table(Some(path), input)?;
+        apply(&table).render(output.to.unwrap_or(from))
+    };
+
+    if let Some(suffix) = &output.backup {
+        let mut backup = path.as_os_str().to_os_string();
+        backup.push(suffix);
+        fs::copy(path, &backup)
+            .map_err(|e| CliError::Io(format!("{}: {}", Message::CreateBackup(Path::new(&backup)), e)))?;
+    }
+    replace_file(path, |out| out.write_all(rendered.as_bytes()))
+        .map_err(|e| CliError::Io(format!("{}: {}", Message::WriteFile(path), e)))?;
+    if !input.quiet {
+        eprintln!("{}", Message::Saved(path));
+    }
+    Ok(())
+}
+
+// 같은 디렉터리의 임시 파일에 다 쓰고 디스크에 반영한 뒤 원본 자리로 옮긴다
+// 쓰는 도중 실패해도 원본은 그대로 남고 임시 파일은 지운다
+fn replace_file(path: &Path, write: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> io::Result<()> {
+    let name = path.file_name().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+    let mut temp_name = OsString::from(".");
+    temp_name.push(name);
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp = path.with_file_name(temp_name);
+
+    let result = fs::File::create_new(&temp).and_then(|file| {
+        // 원본의 권한을 유지한다
+        if let Ok(metadata) = fs::metadata(path) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        let mut out = BufWriter::new(file);
+        write(&mut out)?;
+        let file = out.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()?;
+        fs::rename(&temp, path)
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+// 테이블 정보를 출력하는 하위 명령 공통 처리. report는 출력할 줄과 통과 여부를 돌려준다
+fn report(input: &InputArgs, report: impl Fn(&Table, Format) -> (Vec<String>, bool)) -> Result<ExitCode, CliError> {
+    if !batch::is_batch(&input.paths) {
+        let (table, from) = read_table(single_path(&input.paths), input)?;
+        let (lines, ok) = report(&table, from);
+        for line in &lines {
+            println!("{}", line);
+        }
+        if !ok {
+            return Ok(ExitCode::from(1));
+        }
+        if lines.is_empty() && !input.quiet {
+            eprintln!("{}", Message::NoIssues);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let sources = batch::expand(&input.paths, input.from, None)?;
+    let mut summary = Summary::default();
+    for source in &sources {
+        let result = read_table(Some(&source.path), input).map(|(table, from)| {
+            let (lines, ok) = report(&table, from);
+            for line in &lines {
+                println!("{}: {}", source.path.display(), line);
+            }
+            summary.issues += usize::from(!ok);
+        });
+        summary.record(&source.path, result);
+    }
+    Ok(summary.finish(input.quiet))
+}
+
+// 일괄 처리 결과 집계
+#[derive(Default)]
+struct Summary {
+    succeeded: usize,
+    issues: usize,
+    failures: Vec<(PathBuf, CliError)>,
+}
+
+impl Summary {
+    fn record(&mut self, path: &Path, result: Result<(), CliError>) {
+        match result {
+            Ok(()) => self.succeeded += 1,
+            Err(error) => {
+                eprintln!("{}: {}: {}", Message::Error, path.display(), error);
+                self.failures.push((path.to_path_buf(), error));
+            }
+        }
+    }
+
+    // 요약을 출력하고 종료 코드를 정한다. 실패가 있으면 첫 실패의 종료 코드, 검사 문제만 있으면 1
+    fn finish(self, quiet: bool) -> ExitCode {
+        if !quiet || !self.failures.is_empty() {
+            eprintln!("{}", Message::Summary { succeeded: self.succeeded, failed: self.failures.len() });
+        }
+        for (path, _) in &self.failures {
+            eprintln!("  {}", Message::Failed(path));
+        }
+        match self.failures.first() {
+            Some((_, error)) => ExitCode::from(error.exit_code()),
+            None if self.issues > 0 => ExitCode::from(1),
+            None => ExitCode::SUCCESS,
+        }
+    }
+}
+
+// 입력이 하나뿐일 때의 파일 경로 (없거나 - 이면 stdin)
+fn single_path(paths: &[PathBuf]) -> Option<&Path> {
+    paths.first().map(PathBuf::as_path).filter(|path| *path != Path::new("-"))
+}
+
+fn read_table(path: Option<&Path>, input: &InputArgs) -> Result<(Table, Format), CliError> {
+    let from = input_format(path, input);
+    if !from.is_readable() {
+        return Err(CliError::Usage(Message::UnreadableFormat(from).to_string()));
+    }
+    if input.selector.is_some() && from != Format::Html {
+        return Err(CliError::Usage(Message::SelectorNeedsHtml.to_string()));
+    }
+    if input.stream {
+        if from != Format::Html {
+            return Err(CliError::Usage(Message::StreamNeedsHtml.to_string()));
+        }
+        return Ok((read_table_stream(path)?, from));
+    }
+
+    let text = read_text(path)?;
+    let table = match &input.selector {
+        Some(selector) => Table::from_html_with_selector(&text, selector),
+        None => Table::parse(&text, from),
+    }
+    .map_err(CliError::Format)?;
+    Ok((table, from))
+}
+
+// 파일 또는 stdin에서 html 테이블을 조금씩 읽는다
+fn read_table_stream(path: Option<&Path>) -> Result<Table, CliError> {
+    let table = match path {
+        Some(path) => {
+            let file = fs::File::open(path).map_err(|e| CliError::Io(format!("{}: {}", Message::ReadFile(path), e)))?;
+            Table::from_html_reader(file)
+        }
+        None => Table::from_html_reader(io::stdin().lock()),
+    };
+    table.map_err(CliError::Format)
+}
+
+// 입력 포맷: --from이 없으면 확장자로 추측하고, 알 수 없으면 html
+fn input_format(path: Option<&Path>, input: &InputArgs) -> Format {
+    input.from.unwrap_or_else(|| {
+        path.and_then(|path| path.extension())
+            .and_then(|extension| Format::from_extension(&extension.to_string_lossy()))
+            .unwrap_or(Format::Html)
+    })
+}
+
+// 파일 또는 stdin(path가 None)의 내용을 읽는다
+fn read_text(path: Option<&Path>) -> Result<String, CliError> {
+    match path {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| CliError::Io(format!("{}: {}", Message::ReadFile(path), e))),
+        None => {
+            let mut buffer = String::new();
+            io::stdin()
+                .read_to_string(&mut buffer)
+                .map_err(|e| CliError::Io(format!("{}: {}", Message::ReadStdin, e)))?;
+            Ok(buffer)
+        }
+    }
+}
+
+// 결과 테이블을 문자열로 만들지 않고 파일 또는 stdout에 바로 쓴다
+fn write_table(target: Option<&Path>, table: &Table, format: Format, quiet: bool) -> Result<(), CliError> {
+    write_with(target, quiet, |mut out| table.write_to(format, &mut out))
+}
+
+fn write_with(
+    target: Option<&Path>,
+    quiet: bool,
+    write: impl FnOnce(&mut dyn Write) -> io::Result<()>,
+) -> Result<(), CliError> {
+    match target {
+        Some(path) => {
+            fs::File::create(path)
+                .and_then(|file| {
+                    let mut out = BufWriter::new(file);
+                    write(&mut out)?;
+                    out.flush()
+                })
+                .map_err(|e| CliError::Io(format!("{}: {}", Message::WriteFile(path), e)))?;
+            if !quiet {
+                eprintln!("{}", Message::Saved(path));
+            }
+        }
+        None => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            write(&mut out)
+                .and_then(|_| out.flush())
+                .map_err(|e| CliError::Io(format!("{}: {}", Message::WriteStdout, e)))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<OsString> {
+        list.iter().map(OsString::from).collect()
+    }
+
+    // 예전 사용법은 transpose 하위 명령으로 바뀌어야 함
+    #[test]
+    fn test_legacy_args() {
+        assert_eq!(legacy_args(args(&["bin", "in.html"])), args(&["bin", "transpose", "in.html", "--output", "in.transposed.html"]));
+        assert_eq!(legacy_args(args(&["bin", "in.htm", "out.html"])), args(&["bin", "transpose", "in.htm", "--output", "out.html"]));
+        assert_eq!(legacy_args(args(&["bin", "-"])), args(&["bin", "transpose", "-"]));
+        // 두 번째 인자가 옵션이면 출력 파일로 보지 않는다
+        assert_eq!(
+            legacy_args(args(&["bin", "in.html", "-q"])),
+            args(&["bin", "transpose", "in.html", "--output", "in.transposed.html", "-q"])
+        );
+        assert_eq!(legacy_args(args(&["bin", "-", "-q"])), args(&["bin", "transpose", "-", "-q"]));
+        assert_eq!(
+            legacy_args(args(&["bin", "in.html", "-", "-q"])),
+            args(&["bin", "transpose", "in.html", "--output", "-", "-q"])
+        );
+        assert_eq!(legacy_args(args(&["bin", "inspect", "a.html"])), args(&["bin", "inspect", "a.html"]));
+        assert_eq!(legacy_args(args(&["bin", "--help"])), args(&["bin", "--help"]));
+    }
+
+    // 덮어쓰기는 다 쓴 뒤에만 원본을 바꾸고, 실패하면 원본과 디렉터리를 그대로 둔다
+    #[test]
+    fn test_replace_file() {
+        let dir = std::env::temp_dir().join(format!("html_transpose_replace_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("a.html");
+        fs::write(&path, "원본").unwrap();
+
+        let error = replace_file(&path, |out| {
+            out.write_all(b"half")?;
+            Err(io::ErrorKind::StorageFull.into())
+        });
+        assert_eq!(error.unwrap_err().kind(), io::ErrorKind::StorageFull);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "원본");
+
+        replace_file(&path, |out| out.write_all("결과".as_bytes())).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "결과");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // 하위 명령 인자 파싱
+    #[test]
+    fn test_parse_subcommands() {
+        let cli = Cli::try_parse_from(["bin", "convert", "a.wiki", "--to", "rst", "-q"]).unwrap();
+        let Command::Convert { input, output } = cli.command else {
+            panic!("convert가 아님");
+        };
+        assert_eq!(input.paths, vec![PathBuf::from("a.wiki")]);
+        assert_eq!(output.to, Some(Format::Rst));
+        assert!(input.quiet);
+
+        assert!(Cli::try_parse_from(["bin", "transpose", "--in-place", "-o", "x"]).is_err());
+        assert!(Cli::try_parse_from(["bin", "rotate", "--direction", "sideways"]).is_err());
+    }
+}
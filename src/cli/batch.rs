@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use html_transpose::Format;
+
+use super::CliError;
+use super::messages::Message;
+
+// 일괄 처리할 입력 파일 하나
+pub(super) struct Source {
+    pub(super) path: PathBuf,
+    // --output-dir 아래에 같은 구조로 저장할 때 쓰는 상대 경로
+    pub(super) relative: PathBuf,
+}
+
+// 여러 입력을 처리해야 하는지: 입력이 둘 이상이거나 디렉터리 또는 glob 패턴이 있으면 일괄 처리
+pub(super) fn is_batch(paths: &[PathBuf]) -> bool {
+    paths.len() > 1 || paths.iter().any(|path| path.is_dir() || is_glob(path))
+}
+
+// 파일, 디렉터리(하위 디렉터리 포함), glob 패턴을 입력 파일 목록으로 펼친다
+// 디렉터리 안에서는 읽을 수 있는 포맷의 확장자(--from이 있으면 그 포맷)만 고르고 숨김 항목은 건너뛴다
+// 디렉터리와 glob에서는 이전 실행에서 만든 결과 파일(이름이 .{suffix}로 끝나는 파일)도 건너뛴다
+pub(super) fn expand(paths: &[PathBuf], from: Option<Format>, suffix: Option<&str>) -> Result<Vec<Source>, CliError> {
+    let mut sources = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            collect_dir(path, path, from, suffix, &mut sources)?;
+        } else if is_glob(path) {
+            let pattern = path.to_string_lossy();
+            let entries = glob::glob(&pattern)
+                .map_err(|e| CliError::Usage(format!("{}: {}", Message::InvalidGlob(&pattern), e)))?;
+            let base = glob_base(path);
+            let before = sources.len();
+            for entry in entries {
+                let entry = entry.map_err(|e| CliError::Io(format!("{}: {}", Message::ReadDir(e.path()), e.error())))?;
+                if entry.is_dir() {
+                    collect_dir(&entry, &base, from, suffix, &mut sources)?;
+                } else if !is_previous_output(&entry, suffix) {
+                    let relative = entry.strip_prefix(&base).unwrap_or(&entry).to_path_buf();
+                    sources.push(Source { path: entry, relative });
+                }
+            }
+            if sources.len() == before {
+                return Err(CliError::Usage(Message::NoGlobMatch(&pattern).to_string()));
+            }
+        } else {
+            let relative = path.file_name().map_or_else(|| path.clone(), PathBuf::from);
+            sources.push(Source { path: path.clone(), relative });
+        }
+    }
+    Ok(sources)
+}
+
+fn collect_dir(
+    dir: &Path,
+    base: &Path,
+    from: Option<Format>,
+    suffix: Option<&str>,
+    sources: &mut Vec<Source>,
+) -> Result<(), CliError> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect())
+        .map_err(|e| CliError::Io(format!("{}: {}", Message::ReadDir(dir), e)))?;
+    entries.sort();
+
+    for path in entries {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_dir(&path, base, from, suffix, sources)?;
+            continue;
+        }
+
+        let format = path
+            .extension()
+            .and_then(|extension| Format::from_extension(&extension.to_string_lossy()));
+        let wanted = match (format, from) {
+            (Some(format), Some(from)) => format == from,
+            (Some(format), None) => format.is_readable(),
+            (None, _) => false,
+        };
+        if wanted && !is_previous_output(&path, suffix) {
+            let relative = path.strip_prefix(base).unwrap_or(&path).to_path_buf();
+            sources.push(Source { path, relative });
+        }
+    }
+    Ok(())
+}
+
+// 결과 파일 경로
+// --output-dir가 있으면 그 아래에 입력과 같은 구조로, 없으면 입력 옆에 {이름}.{suffix}.{확장자}로 저장한다
+pub(super) fn output_path(source: &Source, output_dir: Option<&Path>, suffix: &str, to: Format) -> PathBuf {
+    match output_dir {
+        Some(dir) => dir.join(&source.relative).with_extension(to.extension()),
+        None => {
+            let stem = source.path.file_stem().unwrap_or_default().to_string_lossy();
+            source.path.with_file_name(format!("{}.{}.{}", stem, suffix, to.extension()))
+        }
+    }
+}
+
+// 이전 실행에서 입력 옆에 만든 결과 파일인지
+fn is_previous_output(path: &Path, suffix: Option<&str>) -> bool {
+    suffix.is_some_and(|suffix| {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        stem.ends_with(&format!(".{}", suffix))
+    })
+}
+
+fn is_glob(path: &Path) -> bool {
+    !path.exists() && path.to_string_lossy().contains(['*', '?', '['])
+}
+
+// 패턴에서 glob 문자가 처음 나오기 전까지의 디렉터리
+fn glob_base(pattern: &Path) -> PathBuf {
+    let mut base = PathBuf::new();
+    for component in pattern.components() {
+        if let Component::Normal(part) = component
+            && part.to_string_lossy().contains(['*', '?', '['])
+        {
+            break;
+        }
+        base.push(component);
+    }
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 테스트용 임시 디렉터리 구조
+    fn fixture(name: &str, files: &[&str]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("html_transpose_batch_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "<table></table>").unwrap();
+        }
+        root
+    }
+
+    fn relatives(sources: &[Source]) -> Vec<String> {
+        sources.iter().map(|source| source.relative.to_string_lossy().replace('\\', "/")).collect()
+    }
+
+    // 디렉터리는 하위까지 읽을 수 있는 파일만, 숨김 항목과 이전 결과 파일은 제외
+    #[test]
+    fn test_expand_directory() {
+        let root = fixture("dir", &["a.html", "b.transposed.html", "notes.txt", "sub/c.wiki", ".git/d.html"]);
+        let sources = expand(std::slice::from_ref(&root), None, Some("transposed")).unwrap();
+        assert_eq!(relatives(&sources), vec!["a.html", "sub/c.wiki"]);
+
+        let sources = expand(std::slice::from_ref(&root), Some(Format::Html), None).unwrap();
+        assert_eq!(relatives(&sources), vec!["a.html", "b.transposed.html"]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    // glob 패턴은 패턴 앞부분 디렉터리를 기준으로 상대 경로를 만든다
+    #[test]
+    fn test_expand_glob() {
+        let root = fixture("glob", &["x/a.html", "x/a.transposed.html", "y/b.html", "y/c.org"]);
+        let pattern = root.join("*").join("*.html");
+        assert!(is_batch(std::slice::from_ref(&pattern)));
+        let sources = expand(&[pattern], None, Some("transposed")).unwrap();
+        assert_eq!(relatives(&sources), vec!["x/a.html", "y/b.html"]);
+
+        let missing = root.join("*.rst");
+        assert!(matches!(expand(&[missing], None, None), Err(CliError::Usage(_))));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    // 결과 파일 경로
+    #[test]
+    fn test_output_path() {
+        let source = Source { path: PathBuf::from("in/sub/a.html"), relative: PathBuf::from("sub/a.html") };
+        assert_eq!(output_path(&source, None, "transposed", Format::Html), PathBuf::from("in/sub/a.transposed.html"));
+        assert_eq!(output_path(&source, Some(Path::new("out")), "rotated", Format::Org), PathBuf::from("out/sub/a.org"));
+    }
+}
@@ -0,0 +1,357 @@
+use std::io::{self, Read, Write};
+
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+use html_transpose::{Format, Table, TransposeOptions, rewrite_tables};
+
+use super::CliError;
+use super::messages::Message;
+
+// 챕터 안에서 전치할 HTML 테이블을 고르는 선택자
+const MARKED_TABLES: &str = "table[data-transpose]";
+
+// 전치할 테이블을 담는 코드 블록의 언어 이름
+const FENCE_LANG: &str = "transpose";
+
+// 테이블을 찾는 동안 코드 안의 '<'를 잠시 바꿔 두는 문자 (유니코드 사용자 정의 영역)
+const CODE_LT: char = '\u{E000}';
+
+// raw HTML을 그대로 내보내는 렌더러만 지원한다
+pub(super) fn supports(renderer: &str) -> bool {
+    matches!(renderer, "html" | "markdown")
+}
+
+// mdBook 전처리기: stdin으로 [context, book] JSON을 받아 챕터 내용을 바꾼 book JSON을 stdout에 쓴다
+// book 구조는 mdBook 버전마다 조금씩 다르므로 정해진 타입 대신 "Chapter" 항목을 찾아 내용만 바꾼다
+pub(super) fn preprocess(options: &TransposeOptions) -> Result<(), CliError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| CliError::Io(format!("{}: {}", Message::ReadStdin, e)))?;
+    let book = process_input(&input, options)?;
+
+    let mut out = io::stdout().lock();
+    serde_json::to_writer(&mut out, &book)
+        .map_err(io::Error::from)
+        .and_then(|_| out.flush())
+        .map_err(|e| CliError::Io(format!("{}: {}", Message::WriteStdout, e)))
+}
+
+fn process_input(input: &str, options: &TransposeOptions) -> Result<Value, CliError> {
+    let invalid = |detail: String| CliError::Format(format!("{}: {}", Message::MdbookInput, detail));
+    let value: Value = serde_json::from_str(input).map_err(|e| invalid(e.to_string()))?;
+    let Value::Array(mut items) = value else {
+        return Err(invalid("expected [context, book]".to_string()));
+    };
+    if items.len() != 2 {
+        return Err(invalid("expected [context, book]".to_string()));
+    }
+    let mut book = items.pop().unwrap_or_default();
+    process_chapters(&mut book, options).map_err(CliError::Format)?;
+    Ok(book)
+}
+
+// 모든 챕터(하위 챕터 포함)의 content를 바꾼다
+fn process_chapters(value: &mut Value, options: &TransposeOptions) -> Result<(), String> {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Object(chapter)) = map.get_mut("Chapter") {
+                let name = chapter.get("name").and_then(Value::as_str).unwrap_or_default().to_string();
+                if let Some(Value::String(content)) = chapter.get_mut("content") {
+                    match transpose_chapter(content, options).map_err(|e| format!("{}: {}", name, e))? {
+                        Some(transposed) => *content = transposed,
+                        None => eprintln!("{}", Message::MdbookUnchanged(&name)),
+                    }
+                }
+            }
+            map.values_mut().try_for_each(|value| process_chapters(value, options))
+        }
+        Value::Array(items) => items.iter_mut().try_for_each(|value| process_chapters(value, options)),
+        _ => Ok(()),
+    }
+}
+
+// 챕터 마크다운에서 ```transpose 코드 블록과 data-transpose 속성이 있는 HTML 테이블을 전치한다
+// 코드 블록은 전치한 HTML 테이블로 바뀌고, 다른 코드 블록 안의 내용은 건드리지 않는다
+// 원문에서 테이블 위치를 찾지 못하면 None (챕터를 그대로 둔다)
+fn transpose_chapter(markdown: &str, options: &TransposeOptions) -> Result<Option<String>, String> {
+    let mut output = String::with_capacity(markdown.len());
+    let mut text = String::new();
+    let mut lines = markdown.split_inclusive('\n');
+
+    while let Some(line) = lines.next() {
+        let Some((marker, info)) = fence_start(line) else {
+            text.push_str(line);
+            continue;
+        };
+
+        let mut body = String::new();
+        let mut closing = None;
+        for line in lines.by_ref() {
+            if is_fence_end(line, marker) {
+                closing = Some(line);
+                break;
+            }
+            body.push_str(line);
+        }
+
+        let Some(rewritten) = transpose_marked_tables(&std::mem::take(&mut text), options)? else {
+            return Ok(None);
+        };
+        output.push_str(&rewritten);
+
+        let mut words = info.split_whitespace();
+        if words.next() == Some(FENCE_LANG) {
+            let format = match words.next() {
+                Some(name) => name.parse()?,
+                None if body.trim_start().starts_with('<') => Format::Html,
+                None => Format::Markdown,
+            };
+            // HTML 블록이 앞뒤 문단과 붙지 않도록 빈 줄로 감싼다
+            output.push('\n');
+            output.push_str(&Table::parse(&body, format)?.transpose_with(options).to_html());
+            output.push_str("\n\n");
+        } else {
+            output.push_str(line);
+            output.push_str(&body);
+            output.push_str(closing.unwrap_or_default());
+        }
+    }
+    let Some(rewritten) = transpose_marked_tables(&text, options)? else {
+        return Ok(None);
+    };
+    output.push_str(&rewritten);
+    Ok(Some(output))
+}
+
+// 코드 블록 밖의 마크다운에서 data-transpose 테이블만 바꾼다 (그런 테이블이 없으면 그대로)
+// 인라인 코드와 들여쓰기 코드 블록 안의 태그는 테이블로 읽지 않는다. 테이블 위치를 찾지 못하면 None
+fn transpose_marked_tables(text: &str, options: &TransposeOptions) -> Result<Option<String>, String> {
+    if !text.contains("data-transpose") {
+        return Ok(Some(text.to_string()));
+    }
+    if text.contains(CODE_LT) {
+        return Ok(None);
+    }
+    let masked = mask_code(text);
+    let selector = Selector::parse(MARKED_TABLES).map_err(|e| format!("Failed to parse selector: {}", e))?;
+    if Html::parse_document(&masked).select(&selector).next().is_none() {
+        return Ok(Some(text.to_string()));
+    }
+    // 가린 코드는 테이블 밖에만 있으므로 바뀌지 않은 채 남아 있다
+    Ok(rewrite_tables(&masked, MARKED_TABLES, |table| table.transpose_with(options))
+        .ok()
+        .map(|rewritten| rewritten.replace(CODE_LT, "<")))
+}
+
+// 인라인 코드와 들여쓰기 코드 블록 안의 '<'를 CODE_LT로 바꾼다
+// HTML 테이블 안은 마크다운으로 해석되지 않으므로 열린 <table> 안에서는 코드를 찾지 않는다
+fn mask_code(text: &str) -> String {
+    let mut masked = String::with_capacity(text.len());
+    let mut tables = 0usize;
+    // 직전 줄이 빈 줄인지, 들여쓰기 코드 블록인지 (들여쓰기 코드 블록은 문단을 끊지 못한다)
+    let mut blank = true;
+    let mut code_block = false;
+    let mut line_start = true;
+    let mut i = 0;
+
+    while i < text.len() {
+        if line_start {
+            line_start = false;
+            let end = text[i..].find('\n').map_or(text.len(), |offset| i + offset + 1);
+            let line = &text[i..end];
+            let is_blank = line.trim().is_empty();
+            let indented = line.starts_with("    ") || line.starts_with('\t');
+            if tables == 0 && !is_blank && indented && (blank || code_block) {
+                masked.push_str(&line.replace('<', &CODE_LT.to_string()));
+                code_block = true;
+                blank = false;
+                line_start = true;
+                i = end;
+                continue;
+            }
+            // 코드 블록 사이의 빈 줄은 블록을 끝내지 않는다
+            code_block &= is_blank;
+            blank = is_blank;
+        }
+
+        let rest = &text[i..];
+        if tables == 0 && rest.starts_with('`') {
+            let run = rest.bytes().take_while(|b| *b == b'`').count();
+            // 같은 길이의 백틱으로 닫히지 않으면 백틱은 그냥 문자다
+            let end = closing_backticks(&rest[run..], run).map_or(run, |offset| run + offset + run);
+            masked.push_str(&rest[..end].replace('<', &CODE_LT.to_string()));
+            i += end;
+            continue;
+        }
+        if starts_with_tag(rest, "<table") {
+            tables += 1;
+        } else if starts_with_tag(rest, "</table") {
+            tables = tables.saturating_sub(1);
+        }
+        let ch = rest.chars().next().unwrap_or_default();
+        masked.push(ch);
+        line_start = ch == '\n';
+        i += ch.len_utf8();
+    }
+    masked
+}
+
+// 길이가 정확히 run인 백틱 묶음의 위치
+fn closing_backticks(text: &str, run: usize) -> Option<usize> {
+    let mut i = 0;
+    while let Some(offset) = text[i..].find('`') {
+        let start = i + offset;
+        let len = text[start..].bytes().take_while(|b| *b == b'`').count();
+        if len == run {
+            return Some(start);
+        }
+        i = start + len;
+    }
+    None
+}
+
+// 대소문자를 가리지 않고 태그 이름으로 시작하는지 (<tablet 같은 이름은 제외)
+fn starts_with_tag(text: &str, tag: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.get(..tag.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(tag.as_bytes()))
+        && !bytes.get(tag.len()).is_some_and(|b| b.is_ascii_alphanumeric())
+}
+
+// ``` 또는 ~~~ 로 시작하는 코드 블록 시작 줄: (펜스 문자열, 정보 문자열)
+// CommonMark처럼 들여쓰기는 세 칸까지, 펜스는 같은 문자 세 개 이상이다
+fn fence_start(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let fence_len = trimmed.chars().take_while(|c| *c == fence_char).count();
+    if fence_len < 3 {
+        return None;
+    }
+    let (marker, info) = trimmed.split_at(fence_len);
+    // 백틱 펜스의 정보 문자열에는 백틱이 올 수 없다
+    if fence_char == '`' && info.contains('`') {
+        return None;
+    }
+    Some((marker, info.trim()))
+}
+
+// 여는 펜스와 같은 문자로 같은 길이 이상인 닫는 펜스
+fn is_fence_end(line: &str, marker: &str) -> bool {
+    let trimmed = line.trim_start_matches(' ');
+    let fence_char = marker.chars().next().unwrap_or('`');
+    let fence_len = trimmed.chars().take_while(|c| *c == fence_char).count();
+    line.len() - trimmed.len() <= 3 && fence_len >= marker.len() && trimmed[fence_len..].trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> TransposeOptions {
+        TransposeOptions::default()
+    }
+
+    // ```transpose 블록은 전치한 HTML로, 다른 코드 블록은 그대로
+    #[test]
+    fn test_transpose_fences() {
+        let chapter = concat!(
+            "# 제목\n\n",
+            "```transpose\n| a | b |\n|---|---|\n| 1 | 2 |\n```\n",
+            "문단\n\n",
+            "~~~~transpose csv\nx,y\n~~~~\n",
+            "```html\n<table data-transpose><tr><td>1</td><td>2</td></tr></table>\n```\n",
+        );
+        assert_eq!(transpose_chapter(chapter, &options()).unwrap().unwrap(), concat!(
+            "# 제목\n\n",
+            "\n<table><tr><th>a</th><td>1</td></tr><tr><th>b</th><td>2</td></tr></table>\n\n",
+            "문단\n\n",
+            "\n<table><tr><td>x</td></tr><tr><td>y</td></tr></table>\n\n",
+            "```html\n<table data-transpose><tr><td>1</td><td>2</td></tr></table>\n```\n",
+        ));
+
+        assert!(transpose_chapter("```transpose\n없음\n```\n", &options()).is_err());
+        assert!(transpose_chapter("```transpose xlsx\na\n```\n", &options()).is_err());
+    }
+
+    // data-transpose 속성이 있는 테이블만 바뀐다
+    #[test]
+    fn test_transpose_marked_tables() {
+        let chapter = concat!(
+            "`data-transpose` 속성을 붙이면 전치된다.\n\n",
+            "<table data-transpose><tr><td>1</td><td>2</td></tr></table>\n\n",
+            "<table><tr><td>3</td><td>4</td></tr></table>\n",
+        );
+        assert_eq!(transpose_chapter(chapter, &options()).unwrap().unwrap(), concat!(
+            "`data-transpose` 속성을 붙이면 전치된다.\n\n",
+            "<table data-transpose=\"\"><tr><td>1</td></tr><tr><td>2</td></tr></table>\n\n",
+            "<table><tr><td>3</td><td>4</td></tr></table>\n",
+        ));
+        let prose = "`data-transpose` 설명만 있는 챕터\n";
+        assert_eq!(transpose_chapter(prose, &options()).unwrap().unwrap(), prose);
+    }
+
+    // 인라인 코드와 들여쓰기 코드 블록 안의 테이블 예시는 그대로 둔다
+    #[test]
+    fn test_code_examples_untouched() {
+        let chapter = concat!(
+            "예시: `<table data-transpose>` 또는 ``<TABLE data-transpose>`<tr>``\n\n",
+            "    <table data-transpose><tr><td>a</td><td>b</td></tr></table>\n",
+            "\n",
+            "    <table data-transpose><tr><td>c</td><td>d</td></tr></table>\n\n",
+            "<table data-transpose>\n    <tr><td>`1`</td><td>2</td></tr>\n</table>\n",
+        );
+        assert_eq!(transpose_chapter(chapter, &options()).unwrap().unwrap(), concat!(
+            "예시: `<table data-transpose>` 또는 ``<TABLE data-transpose>`<tr>``\n\n",
+            "    <table data-transpose><tr><td>a</td><td>b</td></tr></table>\n",
+            "\n",
+            "    <table data-transpose><tr><td>c</td><td>d</td></tr></table>\n\n",
+            "<table data-transpose=\"\"><tr><td>`1`</td></tr><tr><td>2</td></tr></table>\n",
+        ));
+
+        // 문단에 이어지는 들여쓴 줄은 코드 블록이 아니다
+        assert_eq!(mask_code("문단\n    <b>x</b>\n"), "문단\n    <b>x</b>\n");
+        assert_eq!(mask_code("`닫히지 않은 <b>\n"), "`닫히지 않은 <b>\n");
+    }
+
+    // 원문과 DOM의 테이블 수가 다르면 챕터를 그대로 둔다
+    #[test]
+    fn test_unlocated_tables() {
+        let chapter = "<table data-transpose><tr><td>1</td></tr></table>\n<plaintext><table></table>\n";
+        assert_eq!(transpose_chapter(chapter, &options()).unwrap(), None);
+
+        let fence = "```transpose\n<table><tr><td>1</td><td>2</td></tr></table>\n```\n";
+        let book = serde_json::json!({"sections": [{"Chapter": {"name": "a", "content": format!("{}{}", fence, chapter)}}]});
+        let input = serde_json::json!([{}, book]).to_string();
+        let output = process_input(&input, &options()).unwrap();
+        assert_eq!(output["sections"][0]["Chapter"]["content"], format!("{}{}", fence, chapter));
+    }
+
+    // 전처리기 입력의 모든 챕터와 하위 챕터
+    #[test]
+    fn test_process_input() {
+        let chapter = |name: &str, content: &str, sub_items: Value| {
+            serde_json::json!({"Chapter": {"name": name, "content": content, "sub_items": sub_items}})
+        };
+        let fence = "```transpose\n<table><tr><td>1</td><td>2</td></tr></table>\n```\n";
+        let book = serde_json::json!({
+            "sections": [chapter("a", fence, serde_json::json!([chapter("b", fence, serde_json::json!([]))])), "Separator"],
+            "__non_exhaustive": null,
+        });
+        let input = serde_json::json!([{"root": "/book", "renderer": "html"}, book]).to_string();
+
+        let output = process_input(&input, &options()).unwrap();
+        let expected = "\n<table><tr><td>1</td></tr><tr><td>2</td></tr></table>\n\n";
+        assert_eq!(output["sections"][0]["Chapter"]["content"], expected);
+        assert_eq!(output["sections"][0]["Chapter"]["sub_items"][0]["Chapter"]["content"], expected);
+        assert_eq!(output["sections"][1], "Separator");
+
+        assert!(process_input("{}", &options()).is_err());
+        let broken = serde_json::json!([{}, {"sections": [chapter("깨진 챕터", "```transpose\nx\n```\n", serde_json::json!([]))]}]);
+        let error = process_input(&broken.to_string(), &options()).unwrap_err();
+        assert!(error.to_string().starts_with("깨진 챕터: "), "{}", error);
+    }
+}
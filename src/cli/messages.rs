@@ -0,0 +1,335 @@
+use std::ffi::OsString;
+use std::fmt;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use clap::{Command, ValueEnum};
+
+use html_transpose::Format;
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+// 메시지 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(super) enum Lang {
+    Ko,
+    En,
+}
+
+impl Lang {
+    // --lang 인자, LC_ALL, LC_MESSAGES, LANG 순서로 언어를 정한다. 어디에도 없으면 영어
+    pub(super) fn detect(args: &[OsString]) -> Lang {
+        if let Some(lang) = Lang::from_args(args) {
+            return lang;
+        }
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .map_or(Lang::En, |value| Lang::from_locale(&value))
+    }
+
+    // 명령행의 --lang 값 (clap이 인자를 읽기 전에 도움말 언어를 정해야 하므로 직접 찾는다)
+    fn from_args(args: &[OsString]) -> Option<Lang> {
+        let mut iter = args.iter().skip(1).map(|arg| arg.to_string_lossy());
+        while let Some(arg) = iter.next() {
+            if arg == "--" {
+                break;
+            }
+            let value = match arg.strip_prefix("--lang") {
+                Some("") => iter.next().map(|value| value.to_string()),
+                Some(rest) if rest.starts_with('=') => Some(rest[1..].to_string()),
+                _ => continue,
+            };
+            return value.and_then(|value| Lang::from_str(&value, true).ok());
+        }
+        None
+    }
+
+    // ko_KR.UTF-8 같은 로캘 이름에서 언어를 고른다
+    fn from_locale(locale: &str) -> Lang {
+        if locale.to_ascii_lowercase().starts_with("ko") {
+            Lang::Ko
+        } else {
+            Lang::En
+        }
+    }
+}
+
+pub(super) fn set_lang(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+fn lang() -> Lang {
+    *LANG.get_or_init(|| Lang::detect(&[]))
+}
+
+// CLI가 출력하는 오류/상태 메시지
+pub(super) enum Message<'a> {
+    Error,
+    ReadFile(&'a Path),
+    ReadStdin,
+    WriteFile(&'a Path),
+    WriteStdout,
+    ReadDir(&'a Path),
+    CreateDir(&'a Path),
+    CreateBackup(&'a Path),
+    InvalidGlob(&'a str),
+    NoGlobMatch(&'a str),
+    UnreadableFormat(Format),
+    SelectorNeedsHtml,
+    StreamNeedsHtml,
+    StreamInPlace,
+    ConvertNeedsTo,
+    InPlaceNeedsFile,
+    InPlaceFormatChange,
+    BatchNeedsOutputDir,
+    WatchNeedsFile,
+    WatchNeedsOutput,
+    WatchFailed(&'a Path),
+    Watching(&'a Path),
+    Listen(&'a str),
+    Serving(&'a str),
+    MdbookInput,
+    MdbookUnchanged(&'a str),
+    Saved(&'a Path),
+    NoIssues,
+    Summary { succeeded: usize, failed: usize },
+    Failed(&'a Path),
+    InspectFormat(Format),
+    InspectRows(usize),
+    InspectCols(usize),
+    InspectCells { cells: usize, merged: usize, headers: usize },
+    InspectAttribute(&'a str, &'a str),
+}
+
+impl fmt::Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match lang() {
+            Lang::Ko => self.fmt_ko(f),
+            Lang::En => self.fmt_en(f),
+        }
+    }
+}
+
+impl Message<'_> {
+    fn fmt_ko(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Message::Error => write!(f, "에러"),
+            Message::ReadFile(path) => write!(f, "파일 읽기 실패: {}", path.display()),
+            Message::ReadStdin => write!(f, "stdin에서 읽기 실패"),
+            Message::WriteFile(path) => write!(f, "파일 쓰기 실패: {}", path.display()),
+            Message::WriteStdout => write!(f, "stdout에 쓰기 실패"),
+            Message::ReadDir(path) => write!(f, "디렉터리 읽기 실패: {}", path.display()),
+            Message::CreateDir(path) => write!(f, "디렉터리 만들기 실패: {}", path.display()),
+            Message::CreateBackup(path) => write!(f, "백업 파일 만들기 실패: {}", path.display()),
+            Message::InvalidGlob(pattern) => write!(f, "잘못된 glob 패턴: {}", pattern),
+            Message::NoGlobMatch(pattern) => write!(f, "패턴과 일치하는 파일이 없습니다: {}", pattern),
+            Message::UnreadableFormat(format) => write!(f, "{} 포맷은 입력으로 읽을 수 없습니다", format),
+            Message::SelectorNeedsHtml => write!(f, "--selector는 html 입력에서만 사용할 수 있습니다"),
+            Message::StreamNeedsHtml => write!(f, "--stream은 html 입력에서만 사용할 수 있습니다"),
+            Message::StreamInPlace => write!(f, "--stream은 --in-place와 함께 쓸 수 없습니다"),
+            Message::ConvertNeedsTo => write!(f, "convert에는 --to 포맷이 필요합니다"),
+            Message::InPlaceNeedsFile => write!(f, "--in-place에는 입력 파일이 필요합니다"),
+            Message::InPlaceFormatChange => {
+                write!(f, "html 문서를 --in-place로 고칠 때는 다른 --to 포맷을 쓸 수 없습니다")
+            }
+            Message::BatchNeedsOutputDir => write!(f, "여러 입력을 처리할 때는 -o 대신 --output-dir를 사용하세요"),
+            Message::WatchNeedsFile => write!(f, "watch에는 입력 파일이 하나만 필요합니다"),
+            Message::WatchNeedsOutput => {
+                write!(f, "watch는 --in-place, --output-dir, 입력과 같은 -o 파일을 쓸 수 없습니다")
+            }
+            Message::WatchFailed(path) => write!(f, "파일 감시 실패: {}", path.display()),
+            Message::Watching(path) => write!(f, "{} 파일의 변경을 감시합니다. 끝내려면 Ctrl+C를 누르세요.", path.display()),
+            Message::Listen(addr) => write!(f, "{} 주소에서 요청을 받을 수 없습니다", addr),
+            Message::Serving(addr) => write!(f, "http://{} 에서 요청을 받습니다. 끝내려면 Ctrl+C를 누르세요.", addr),
+            Message::MdbookInput => write!(f, "mdBook 전처리기 입력을 읽을 수 없습니다"),
+            Message::MdbookUnchanged(name) => write!(f, "{}: 원문에서 테이블 위치를 찾지 못해 챕터를 바꾸지 않았습니다", name),
+            Message::Saved(path) => write!(f, "결과가 {} 파일에 저장되었습니다.", path.display()),
+            Message::NoIssues => write!(f, "문제가 없습니다."),
+            Message::Summary { succeeded, failed } => write!(f, "성공 {}개, 실패 {}개", succeeded, failed),
+            Message::Failed(path) => write!(f, "실패: {}", path.display()),
+            Message::InspectFormat(format) => write!(f, "포맷: {}", format),
+            Message::InspectRows(rows) => write!(f, "행: {}", rows),
+            Message::InspectCols(cols) => write!(f, "열: {}", cols),
+            Message::InspectCells { cells, merged, headers } => {
+                write!(f, "셀: {} (병합 {}, 헤더 {})", cells, merged, headers)
+            }
+            Message::InspectAttribute(name, value) => write!(f, "속성: {}=\"{}\"", name, value),
+        }
+    }
+
+    fn fmt_en(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Message::Error => write!(f, "error"),
+            Message::ReadFile(path) => write!(f, "failed to read {}", path.display()),
+            Message::ReadStdin => write!(f, "failed to read stdin"),
+            Message::WriteFile(path) => write!(f, "failed to write {}", path.display()),
+            Message::WriteStdout => write!(f, "failed to write to stdout"),
+            Message::ReadDir(path) => write!(f, "failed to read directory {}", path.display()),
+            Message::CreateDir(path) => write!(f, "failed to create directory {}", path.display()),
+            Message::CreateBackup(path) => write!(f, "failed to create backup {}", path.display()),
+            Message::InvalidGlob(pattern) => write!(f, "invalid glob pattern {}", pattern),
+            Message::NoGlobMatch(pattern) => write!(f, "no files match {}", pattern),
+            Message::UnreadableFormat(format) => write!(f, "{} can't be used as an input format", format),
+            Message::SelectorNeedsHtml => write!(f, "--selector only works with html input"),
+            Message::StreamNeedsHtml => write!(f, "--stream only works with html input"),
+            Message::StreamInPlace => write!(f, "--stream can't be used with --in-place"),
+            Message::ConvertNeedsTo => write!(f, "convert requires --to"),
+            Message::InPlaceNeedsFile => write!(f, "--in-place requires an input file"),
+            Message::InPlaceFormatChange => write!(f, "--to can't change the format of an html document edited in place"),
+            Message::BatchNeedsOutputDir => write!(f, "use --output-dir instead of -o with multiple inputs"),
+            Message::WatchNeedsFile => write!(f, "watch requires exactly one input file"),
+            Message::WatchNeedsOutput => {
+                write!(f, "watch can't be used with --in-place, --output-dir or -o pointing at the input")
+            }
+            Message::WatchFailed(path) => write!(f, "failed to watch {}", path.display()),
+            Message::Watching(path) => write!(f, "Watching {} for changes. Press Ctrl+C to stop.", path.display()),
+            Message::Listen(addr) => write!(f, "failed to listen on {}", addr),
+            Message::Serving(addr) => write!(f, "Listening on http://{}. Press Ctrl+C to stop.", addr),
+            Message::MdbookInput => write!(f, "invalid mdBook preprocessor input"),
+            Message::MdbookUnchanged(name) => {
+                write!(f, "{}: could not locate the tables in the source; chapter left unchanged", name)
+            }
+            Message::Saved(path) => write!(f, "Saved to {}", path.display()),
+            Message::NoIssues => write!(f, "No issues found."),
+            Message::Summary { succeeded, failed } => write!(f, "{} succeeded, {} failed", succeeded, failed),
+            Message::Failed(path) => write!(f, "failed: {}", path.display()),
+            Message::InspectFormat(format) => write!(f, "format: {}", format),
+            Message::InspectRows(rows) => write!(f, "rows: {}", rows),
+            Message::InspectCols(cols) => write!(f, "columns: {}", cols),
+            Message::InspectCells { cells, merged, headers } => {
+                write!(f, "cells: {} ({} merged, {} header)", cells, merged, headers)
+            }
+            Message::InspectAttribute(name, value) => write!(f, "attribute: {}=\"{}\"", name, value),
+        }
+    }
+}
+
+// 하위 명령 이름 또는 인자 id에 대한 도움말
+fn help(lang: Lang, id: &str) -> Option<&'static str> {
+    let text = match (lang, id) {
+        (Lang::Ko, "html_transpose") => "HTML 테이블을 전치, 회전, 변환하는 도구",
+        (Lang::En, "html_transpose") => "Transpose, rotate and convert HTML tables",
+        (Lang::Ko, "transpose") => "테이블의 행과 열을 바꾼다",
+        (Lang::En, "transpose") => "Swap the rows and columns of a table",
+        (Lang::Ko, "rotate") => "테이블을 90도 또는 180도 회전한다",
+        (Lang::En, "rotate") => "Rotate a table by 90 or 180 degrees",
+        (Lang::Ko, "convert") => "테이블을 다른 포맷으로 변환한다",
+        (Lang::En, "convert") => "Convert a table to another format",
+        (Lang::Ko, "validate") => "headers/id 연결을 검사한다 (문제가 있으면 종료 코드 1)",
+        (Lang::En, "validate") => "Check headers/id references (exit code 1 on issues)",
+        (Lang::Ko, "inspect") => "테이블 크기와 셀 정보를 출력한다",
+        (Lang::En, "inspect") => "Print the table size and cell counts",
+        (Lang::Ko, "watch") => "입력 파일이 바뀔 때마다 다시 전치한다",
+        (Lang::En, "watch") => "Transpose again whenever the input file changes",
+        (Lang::Ko, "serve") => "POST /transpose, POST /convert 요청을 처리하는 로컬 HTTP 서버를 실행한다",
+        (Lang::En, "serve") => "Run a local HTTP server handling POST /transpose and POST /convert",
+        (Lang::Ko, "listen") => "서버 주소와 포트",
+        (Lang::En, "listen") => "Address and port to listen on",
+        (Lang::Ko, "mdbook") => "mdBook 전처리기로 동작한다 (book.toml의 command로 지정)",
+        (Lang::En, "mdbook") => "Run as an mdBook preprocessor (set as command in book.toml)",
+        (Lang::Ko, "supports") => "렌더러를 지원하는지 종료 코드로 알린다 (mdBook이 호출)",
+        (Lang::En, "supports") => "Report through the exit code whether a renderer is supported (called by mdBook)",
+        (Lang::Ko, "renderer") => "mdBook 렌더러 이름",
+        (Lang::En, "renderer") => "Name of the mdBook renderer",
+        (Lang::Ko, "debounce") => "연속 저장을 하나로 모으는 대기 시간(밀리초)",
+        (Lang::En, "debounce") => "Quiet period in milliseconds that merges rapid saves",
+        (Lang::Ko, "lang") => "메시지 언어 (기본값: LC_ALL, LC_MESSAGES, LANG에서 결정)",
+        (Lang::En, "lang") => "Message language (default: from LC_ALL, LC_MESSAGES or LANG)",
+        (Lang::Ko, "paths") => "입력 파일, 디렉터리 또는 glob 패턴 (생략하거나 - 이면 stdin)",
+        (Lang::En, "paths") => "Input files, directories or glob patterns (stdin when omitted or -)",
+        (Lang::Ko, "from") => "입력 포맷 (기본값: 확장자로 추측, 없으면 html)",
+        (Lang::En, "from") => "Input format (default: guessed from the extension, else html)",
+        (Lang::Ko, "selector") => "읽을 테이블을 고르는 CSS 선택자 (html 입력만)",
+        (Lang::En, "selector") => "CSS selector choosing the table (html input only)",
+        (Lang::Ko, "stream") => "문서 전체를 메모리에 올리지 않고 첫 번째 테이블을 읽는다 (html 입력만)",
+        (Lang::En, "stream") => "Read the first table without loading the whole document (html input only)",
+        (Lang::Ko, "quiet") => "진행 메시지를 출력하지 않는다",
+        (Lang::En, "quiet") => "Don't print progress messages",
+        (Lang::Ko, "to") => "출력 포맷 (기본값: 입력 포맷, convert는 필수)",
+        (Lang::En, "to") => "Output format (default: the input format, required by convert)",
+        (Lang::Ko, "output") => "출력 파일 (기본값: stdout)",
+        (Lang::En, "output") => "Output file (default: stdout)",
+        (Lang::Ko, "output_dir") => "입력과 같은 구조로 결과를 저장할 디렉터리",
+        (Lang::En, "output_dir") => "Directory receiving the results, mirroring the input structure",
+        (Lang::Ko, "in_place") => "입력 파일을 결과로 덮어쓴다 (html 문서는 선택된 테이블만 바꾼다)",
+        (Lang::En, "in_place") => "Overwrite the input file (only the selected tables of an html document)",
+        (Lang::Ko, "backup") => "덮어쓰기 전에 원본을 <입력파일><SUFFIX>로 복사한다 (기본값: .bak)",
+        (Lang::En, "backup") => "Copy the original to <input><SUFFIX> before overwriting (default: .bak)",
+        (Lang::Ko, "direction") => "회전 방향",
+        (Lang::En, "direction") => "Rotation direction",
+        (Lang::Ko, "infer_scope") => "scope가 없는 헤더 셀에 위치로 추측한 scope를 붙인다",
+        (Lang::En, "infer_scope") => "Add a scope guessed from the position to header cells without one",
+        (Lang::Ko, "no_swap_scope") => "scope=row/col을 바꾸지 않는다",
+        (Lang::En, "no_swap_scope") => "Don't swap scope=row/col",
+        (Lang::Ko, "swap_axis_styles") => "width/height, align/valign과 방향 관련 인라인 스타일을 바꾼다",
+        (Lang::En, "swap_axis_styles") => "Swap width/height, align/valign and direction-sensitive inline styles",
+        (Lang::Ko, "generate_headers") => "데이터 셀에 headers 속성을 생성한다",
+        (Lang::En, "generate_headers") => "Generate headers attributes on data cells",
+        (Lang::Ko, "id_prefix") => "id와 headers 참조에 붙일 접두사",
+        (Lang::En, "id_prefix") => "Prefix added to ids and headers references",
+        _ => return None,
+    };
+    Some(text)
+}
+
+// derive로 만든 명령 정의에 선택한 언어의 도움말을 붙인다
+pub(super) fn localize(mut command: Command, lang: Lang) -> Command {
+    if let Some(about) = help(lang, command.get_name()) {
+        command = command.about(about);
+    }
+    let ids: Vec<String> = command.get_arguments().map(|arg| arg.get_id().to_string()).collect();
+    for id in ids {
+        if let Some(text) = help(lang, &id) {
+            command = command.mut_arg(id, |arg| arg.help(text));
+        }
+    }
+    let names: Vec<String> = command.get_subcommands().map(|sub| sub.get_name().to_string()).collect();
+    for name in names {
+        command = command.mut_subcommand(name, |sub| localize(sub, lang));
+    }
+    command
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+    use crate::cli::Cli;
+
+    fn args(list: &[&str]) -> Vec<OsString> {
+        list.iter().map(OsString::from).collect()
+    }
+
+    // --lang 인자와 로캘 이름
+    #[test]
+    fn test_detect_lang() {
+        assert_eq!(Lang::from_args(&args(&["bin", "inspect", "--lang", "ko", "a.html"])), Some(Lang::Ko));
+        assert_eq!(Lang::from_args(&args(&["bin", "--lang=EN"])), Some(Lang::En));
+        assert_eq!(Lang::from_args(&args(&["bin", "--", "--lang=ko"])), None);
+        assert_eq!(Lang::from_locale("ko_KR.UTF-8"), Lang::Ko);
+        assert_eq!(Lang::from_locale("C.UTF-8"), Lang::En);
+        assert_eq!(Lang::detect(&args(&["bin", "--lang", "ko"])), Lang::Ko);
+    }
+
+    // 모든 하위 명령과 인자에 두 언어의 도움말이 있어야 함
+    #[test]
+    fn test_help_catalog_complete() {
+        fn check(command: &Command) {
+            for lang in [Lang::Ko, Lang::En] {
+                assert!(help(lang, command.get_name()).is_some(), "{:?} {}", lang, command.get_name());
+                for arg in command.get_arguments() {
+                    assert!(help(lang, arg.get_id().as_str()).is_some(), "{:?} {}", lang, arg.get_id());
+                }
+            }
+            command.get_subcommands().for_each(check);
+        }
+        check(&Cli::command());
+
+        let localized = localize(Cli::command(), Lang::En);
+        let transpose = localized.find_subcommand("transpose").unwrap();
+        assert_eq!(transpose.get_about().unwrap().to_string(), "Swap the rows and columns of a table");
+    }
+}
@@ -0,0 +1,225 @@
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use html_transpose::{Format, Table, TransposeOptions};
+
+use super::CliError;
+use super::messages::Message;
+
+// 요청 본문 크기 제한
+const MAX_BODY: u64 = 64 * 1024 * 1024;
+
+// 테이블 크기(행 수 x 열 수) 상한. 작은 요청의 큰 병합 셀로 메모리를 다 쓰지 않도록 이보다 큰 테이블은 422로 거절한다
+const MAX_CELLS: usize = 1 << 25;
+
+// 작업 스레드 수 상한 (로컬 도구용이므로 많이 둘 필요가 없다)
+const MAX_WORKERS: usize = 8;
+
+// 로컬 HTTP 서버. 끝낼 때까지 요청을 처리한다
+//   POST /transpose[?from=&to=]  테이블을 전치한다 (to를 생략하면 입력 포맷)
+//   POST /convert?to=[&from=]    테이블을 다른 포맷으로 바꾼다
+// 입력 포맷은 from 매개변수, Content-Type 순서로 정하고, 둘 다 없으면 html
+// 응답 오류 메시지는 호출하는 도구가 읽을 수 있도록 언어 설정과 관계없이 영어로 보낸다
+pub(super) fn serve(listen: &str, options: &TransposeOptions, quiet: bool) -> Result<(), CliError> {
+    let server = Server::http(listen).map_err(|e| CliError::Io(format!("{}: {}", Message::Listen(listen), e)))?;
+    if !quiet {
+        eprintln!("{}", Message::Serving(&server.server_addr().to_string()));
+    }
+    run(&server, options, quiet);
+    Ok(())
+}
+
+fn run(server: &Server, options: &TransposeOptions, quiet: bool) {
+    let workers = thread::available_parallelism().map_or(1, |n| n.get()).min(MAX_WORKERS);
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    handle(request, options, quiet);
+                }
+            });
+        }
+    });
+}
+
+fn handle(mut request: Request, options: &TransposeOptions, quiet: bool) {
+    let content_type = request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv("Content-Type"))
+        .map(|header| header.value.as_str().to_string());
+
+    let mut body = Vec::new();
+    let reply = match request.as_reader().take(MAX_BODY + 1).read_to_end(&mut body) {
+        Err(error) => Reply::error(400, format!("Failed to read request body: {}", error)),
+        Ok(_) if body.len() as u64 > MAX_BODY => Reply::error(413, format!("Request body is larger than {} bytes", MAX_BODY)),
+        Ok(_) => match String::from_utf8(body) {
+            // 라이브러리가 패닉해도 작업 스레드가 끝나지 않도록 500으로 응답한다
+            Ok(body) => panic::catch_unwind(AssertUnwindSafe(|| {
+                route(request.method(), request.url(), content_type.as_deref(), &body, options)
+            }))
+            .unwrap_or_else(|_| Reply::error(500, "Internal error while processing the table".to_string())),
+            Err(_) => Reply::error(400, "Request body is not valid UTF-8".to_string()),
+        },
+    };
+
+    if !quiet {
+        eprintln!("{} {} {}", request.method(), request.url(), reply.status);
+    }
+    let mut response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(header("Content-Type", reply.content_type));
+    if reply.status == 405 {
+        response = response.with_header(header("Allow", "POST"));
+    }
+    // 클라이언트가 먼저 연결을 끊은 경우이므로 무시한다
+    let _ = request.respond(response);
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name, value).expect("static header is valid")
+}
+
+// 응답 상태, Content-Type, 본문
+#[derive(Debug)]
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Reply {
+    fn error(status: u16, message: String) -> Reply {
+        Reply { status, content_type: "text/plain; charset=utf-8", body: message + "\n" }
+    }
+}
+
+// 경로와 매개변수에 따라 요청을 처리한다
+fn route(method: &Method, url: &str, content_type: Option<&str>, body: &str, options: &TransposeOptions) -> Reply {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let transpose = match path {
+        "/transpose" => true,
+        "/convert" => false,
+        _ => return Reply::error(404, format!("Not found: {} (use POST /transpose or POST /convert)", path)),
+    };
+    if *method != Method::Post {
+        return Reply::error(405, format!("Method {} not allowed (use POST)", method));
+    }
+
+    let mut from = content_type.and_then(Format::from_media_type).unwrap_or(Format::Html);
+    let mut to = None;
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let format = match value.parse::<Format>() {
+            Ok(format) => format,
+            Err(error) => return Reply::error(400, error),
+        };
+        match name {
+            "from" => from = format,
+            "to" => to = Some(format),
+            _ => return Reply::error(400, format!("Unknown parameter \"{}\" (expected from or to)", name)),
+        }
+    }
+    if !from.is_readable() {
+        return Reply::error(415, format!("Reading {} tables is not supported", from));
+    }
+    let to = match to {
+        Some(to) => to,
+        None if transpose => from,
+        None => return Reply::error(400, "POST /convert requires ?to=FORMAT".to_string()),
+    };
+
+    match Table::parse_with_limit(body, from, MAX_CELLS) {
+        Ok(table) => {
+            let table = if transpose { table.transpose_with(options) } else { table };
+            Reply { status: 200, content_type: to.media_type(), body: table.render(to) }
+        }
+        Err(error) => Reply::error(422, error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::net::TcpStream;
+
+    use super::*;
+
+    fn post(url: &str, content_type: Option<&str>, body: &str) -> Reply {
+        route(&Method::Post, url, content_type, body, &TransposeOptions::default())
+    }
+
+    // 경로, 포맷 선택, 오류 상태 코드
+    #[test]
+    fn test_route() {
+        let reply = post("/transpose", None, "<table><tr><td>1</td><td>2</td></tr></table>");
+        assert_eq!((reply.status, reply.content_type), (200, "text/html; charset=utf-8"));
+        assert_eq!(reply.body, "<table><tr><td>1</td></tr><tr><td>2</td></tr></table>");
+
+        let reply = post("/transpose", Some("text/csv"), "a,b\r\n1,2\r\n");
+        assert_eq!((reply.status, reply.content_type), (200, "text/csv; charset=utf-8"));
+        assert_eq!(reply.body, "a,1\r\nb,2\r\n");
+
+        let reply = post("/convert?to=markdown", Some("text/plain"), "a,b\n");
+        assert_eq!(reply.status, 422);
+        let reply = post("/convert?from=csv&to=md", Some("text/plain"), "a,b\n");
+        assert_eq!((reply.status, reply.content_type), (200, "text/markdown; charset=utf-8"));
+        assert_eq!(reply.body, "| a   | b   |\n| --- | --- |\n");
+
+        assert_eq!(post("/convert", None, "<table></table>").status, 400);
+        assert_eq!(post("/convert?to=xlsx", None, "").status, 400);
+        assert_eq!(post("/transpose?from=rst", None, "").status, 415);
+        assert_eq!(post("/", None, "").status, 404);
+        let options = TransposeOptions::default();
+        assert_eq!(route(&Method::Get, "/transpose", None, "", &options).status, 405);
+    }
+
+    // localhost에서 실제 서버에 요청을 보낸다
+    #[test]
+    fn test_serve_localhost() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || run(&server, &TransposeOptions::default(), true));
+
+        let request = |text: &str| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(text.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let body = "| a | b |\n|---|---|\n| 1 | 2 |\n";
+        let response = request(&format!(
+            "POST /convert?to=html HTTP/1.1\r\nHost: localhost\r\nContent-Type: text/markdown\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ));
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert!(response.contains("Content-Type: text/html; charset=utf-8"));
+        assert!(response.ends_with("<table><tr><th>a</th><th>b</th></tr><tr><td>1</td><td>2</td></tr></table>"));
+
+        let response = request("GET /transpose HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 405"), "{}", response);
+        assert!(response.contains("Allow: POST"));
+
+        // 큰 병합 크기는 상한으로 자르고, 상한 안에서도 너무 큰 테이블은 422로 거절한다
+        for (body, status) in [
+            (r#"<table><tr><td rowspan="18446744073709551615">A</td></tr></table>"#, "200"),
+            (r#"<table><tr><td colspan="4000000000">A</td></tr></table>"#, "200"),
+            (r#"<table><tr><td rowspan="65534" colspan="1000">A</td></tr></table>"#, "422"),
+        ] {
+            let response = request(&format!(
+                "POST /transpose HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            ));
+            assert!(response.starts_with(&format!("HTTP/1.1 {}", status)), "{}", response);
+        }
+        // 그 뒤에도 서버가 계속 요청을 처리한다
+        assert!(request("GET /transpose HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").starts_with("HTTP/1.1 405"));
+    }
+}
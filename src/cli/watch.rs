@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use super::CliError;
+use super::messages::Message;
+
+// 입력 파일이 바뀔 때마다 run을 다시 실행한다. 처음 한 번은 바로 실행
+// 연속 저장은 debounce 동안 새 이벤트가 없을 때까지 모아서 한 번만 실행하고,
+// 편집기가 임시 파일을 만든 뒤 이름을 바꿔 저장해도 따라가도록 파일 대신 상위 디렉터리를 감시한다
+pub(super) fn watch(path: &Path, debounce: Duration, quiet: bool, mut run: impl FnMut()) -> Result<(), CliError> {
+    let path = path
+        .canonicalize()
+        .map_err(|e| CliError::Io(format!("{}: {}", Message::ReadFile(path), e)))?;
+    let dir = path.parent().map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+
+    let (sender, receiver) = mpsc::channel();
+    // 감시가 끝날 때까지 watcher가 살아 있어야 이벤트가 들어온다
+    let _watcher = notify::recommended_watcher(sender)
+        .and_then(|mut watcher| watcher.watch(&dir, RecursiveMode::NonRecursive).map(|_| watcher))
+        .map_err(|e| CliError::Io(format!("{}: {}", Message::WatchFailed(&path), e)))?;
+
+    run();
+    if !quiet {
+        eprintln!("{}", Message::Watching(&path));
+    }
+
+    while let Ok(event) = receiver.recv() {
+        if !is_change(&event, &path) {
+            continue;
+        }
+        if !settle(&receiver, &path, debounce) {
+            return Ok(());
+        }
+        run();
+    }
+    Ok(())
+}
+
+// 입력 파일의 변경이 debounce 동안 더 없을 때까지 기다린다. 채널이 닫히면 false
+// 같은 디렉터리의 다른 파일(-o 출력 포함) 이벤트는 대기 시간을 늘리지 않는다
+fn settle(receiver: &Receiver<notify::Result<Event>>, path: &Path, debounce: Duration) -> bool {
+    let mut deadline = Instant::now() + debounce;
+    loop {
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(event) if is_change(&event, path) => deadline = Instant::now() + debounce,
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => return true,
+            Err(RecvTimeoutError::Disconnected) => return false,
+        }
+    }
+}
+
+// 감시 중인 파일의 내용이 바뀌었거나 새로 만들어졌는지. 감시 오류는 출력만 하고 계속한다
+fn is_change(event: &notify::Result<Event>, path: &Path) -> bool {
+    match event {
+        Ok(event) => {
+            matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                && event.paths.iter().any(|changed| changed == path)
+        }
+        Err(error) => {
+            eprintln!("{}: {}", Message::Error, error);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use notify::event::{AccessKind, CreateKind, DataChange, ModifyKind};
+
+    use super::*;
+
+    // 감시 중인 파일의 생성/수정만 변경으로 본다
+    #[test]
+    fn test_is_change() {
+        let path = Path::new("/tmp/input.html");
+        let event = |kind, changed: &str| Ok(Event::new(kind).add_path(PathBuf::from(changed)));
+
+        assert!(is_change(&event(EventKind::Modify(ModifyKind::Data(DataChange::Content)), "/tmp/input.html"), path));
+        assert!(is_change(&event(EventKind::Create(CreateKind::File), "/tmp/input.html"), path));
+        assert!(!is_change(&event(EventKind::Modify(ModifyKind::Any), "/tmp/out.html"), path));
+        assert!(!is_change(&event(EventKind::Access(AccessKind::Any), "/tmp/input.html"), path));
+    }
+
+    // 다른 파일의 이벤트가 계속 들어와도 대기는 debounce 뒤에 끝난다
+    #[test]
+    fn test_settle_ignores_other_files() {
+        let path = Path::new("/tmp/input.html");
+        let (sender, receiver) = mpsc::channel();
+        let noise = std::thread::spawn(move || {
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_millis(500) {
+                let event = Event::new(EventKind::Modify(ModifyKind::Any)).add_path(PathBuf::from("/tmp/out.html"));
+                if sender.send(Ok(event)).is_err() {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+        });
+
+        let start = Instant::now();
+        assert!(settle(&receiver, path, Duration::from_millis(50)));
+        assert!(start.elapsed() < Duration::from_millis(400));
+        drop(receiver);
+        noise.join().unwrap();
+
+        let (sender, receiver) = mpsc::channel::<notify::Result<Event>>();
+        drop(sender);
+        assert!(!settle(&receiver, path, Duration::from_millis(50)));
+    }
+}
@@ -0,0 +1,201 @@
+// style 속성의 CSS 선언 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declaration {
+    pub property: String,
+    pub value: String,
+    pub important: bool,
+}
+
+impl Declaration {
+    pub fn new(property: &str, value: &str) -> Declaration {
+        Declaration {
+            property: property.to_string(),
+            value: value.to_string(),
+            important: false,
+        }
+    }
+}
+
+// style 속성 값을 선언 목록으로 파싱
+// 따옴표 문자열과 괄호(url(), calc() 등) 안의 ; 와 : 는 구분자로 보지 않고, 주석은 버린다.
+// 모르는 속성도 그대로 남기며, 콜론이 없는 잘못된 조각만 브라우저처럼 무시한다
+pub fn parse_style(style: &str) -> Vec<Declaration> {
+    split_top_level(&strip_comments(style), ';')
+        .into_iter()
+        .filter_map(|part| {
+            let (property, value) = split_once_top_level(&part, ':')?;
+            let property = property.trim();
+            if property.is_empty() {
+                return None;
+            }
+
+            let mut value = value.trim();
+            let mut important = false;
+            if let Some(bang) = value.rfind('!')
+                && value[bang + 1..].trim().eq_ignore_ascii_case("important")
+            {
+                important = true;
+                value = value[..bang].trim_end();
+            }
+
+            Some(Declaration {
+                property: property.to_string(),
+                value: value.to_string(),
+                important,
+            })
+        })
+        .collect()
+}
+
+// 선언 목록을 style 속성 값으로 직렬화
+pub fn serialize_style(declarations: &[Declaration]) -> String {
+    declarations
+        .iter()
+        .map(|declaration| {
+            if declaration.important {
+                format!("{}: {} !important", declaration.property, declaration.value)
+            } else {
+                format!("{}: {}", declaration.property, declaration.value)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// 값을 공백 기준으로 나눈다 (괄호와 따옴표 안의 공백은 유지)
+pub(crate) fn split_values(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut scanner = Scanner::default();
+    for ch in value.chars() {
+        if scanner.at_top_level() && ch.is_whitespace() {
+            if !current.is_empty() {
+                parts.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        scanner.advance(ch);
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+// 따옴표와 괄호 중첩 상태 추적
+#[derive(Default)]
+struct Scanner {
+    quote: Option<char>,
+    escaped: bool,
+    depth: usize,
+}
+
+impl Scanner {
+    fn at_top_level(&self) -> bool {
+        self.quote.is_none() && self.depth == 0
+    }
+
+    fn advance(&mut self, ch: char) {
+        if self.escaped {
+            self.escaped = false;
+            return;
+        }
+        match (self.quote, ch) {
+            (_, '\\') => self.escaped = true,
+            (Some(quote), _) if ch == quote => self.quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => self.quote = Some(ch),
+            (None, '(') => self.depth += 1,
+            (None, ')') => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+}
+
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut scanner = Scanner::default();
+    for ch in text.chars() {
+        if ch == separator && scanner.at_top_level() && !scanner.escaped {
+            parts.push(std::mem::take(&mut current));
+            continue;
+        }
+        scanner.advance(ch);
+        current.push(ch);
+    }
+    parts.push(current);
+    parts
+}
+
+fn split_once_top_level(text: &str, separator: char) -> Option<(String, String)> {
+    let mut scanner = Scanner::default();
+    for (idx, ch) in text.char_indices() {
+        if ch == separator && scanner.at_top_level() && !scanner.escaped {
+            return Some((text[..idx].to_string(), text[idx + ch.len_utf8()..].to_string()));
+        }
+        scanner.advance(ch);
+    }
+    None
+}
+
+// /* ... */ 주석 제거 (문자열 안의 /* 는 유지)
+fn strip_comments(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut scanner = Scanner::default();
+    let mut rest = text;
+    while let Some(ch) = rest.chars().next() {
+        if scanner.quote.is_none() && !scanner.escaped && rest.starts_with("/*") {
+            rest = rest.find("*/").map_or("", |end| &rest[end + 2..]);
+            continue;
+        }
+        scanner.advance(ch);
+        output.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 기본 선언, !important, 모르는 속성, 커스텀 속성
+    #[test]
+    fn test_parse_declarations() {
+        let declarations = parse_style("color: red; WIDTH:10px ! important;; -webkit-foo: bar; --gap: 4px");
+        assert_eq!(declarations, vec![
+            Declaration::new("color", "red"),
+            Declaration { property: "WIDTH".to_string(), value: "10px".to_string(), important: true },
+            Declaration::new("-webkit-foo", "bar"),
+            Declaration::new("--gap", "4px"),
+        ]);
+    }
+
+    // 문자열/괄호 안의 ; 와 : 는 구분자가 아니고 주석은 제거
+    #[test]
+    fn test_parse_quotes_parens_comments() {
+        let declarations = parse_style(r#"background: url("a;b:c.png") /* x; y */; content: 'a;b'; broken; font-family: "A \" B""#);
+        assert_eq!(declarations, vec![
+            Declaration::new("background", r#"url("a;b:c.png")"#),
+            Declaration::new("content", "'a;b'"),
+            Declaration::new("font-family", r#""A \" B""#),
+        ]);
+    }
+
+    // 직렬화 후 다시 파싱해도 같아야 함
+    #[test]
+    fn test_serialize_roundtrip() {
+        let style = "margin: 0 auto !important; background: url(a;b.png)";
+        let declarations = parse_style(style);
+        assert_eq!(serialize_style(&declarations), style);
+        assert_eq!(parse_style(&serialize_style(&declarations)), declarations);
+    }
+
+    // 값 분리는 괄호 안 공백을 유지
+    #[test]
+    fn test_split_values() {
+        assert_eq!(split_values(" calc(1px + 2px)  3px 'a b' "), vec!["calc(1px + 2px)", "3px", "'a b'"]);
+    }
+}
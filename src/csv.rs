@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::table::{Table, TableBuilder};
+
+// CSV 파싱 (RFC 4180): 쉼표 구분, 큰따옴표로 감싼 필드 안의 쉼표/줄바꿈, "" 이스케이프
+// CSV에는 헤더 개념이 없으므로 모든 셀을 데이터 셀(td)로 읽는다. 빈 줄은 건너뛴다
+pub(crate) fn parse(text: &str, max_cells: Option<usize>) -> Result<Table, String> {
+    let text = text.strip_prefix('\u{FEFF}').unwrap_or(text);
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    // 현재 줄에 내용이 있었는지 (빈 줄과 빈 필드 하나짜리 줄을 구분)
+    let mut line_used = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => {
+                line_used = true;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => field.push(c),
+                        None => return Err(format!("Unterminated quoted field in CSV row {}", rows.len() + 1)),
+                    }
+                }
+            }
+            ',' => {
+                line_used = true;
+                row.push(std::mem::take(&mut field));
+            }
+            '\r' | '\n' => {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                if line_used || !field.is_empty() {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                line_used = false;
+            }
+            c => field.push(c),
+        }
+    }
+    if line_used || !field.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err("No CSV rows found".to_string());
+    }
+
+    let mut builder = TableBuilder::with_limit(max_cells);
+    for row in rows {
+        builder.start_row();
+        for content in row {
+            builder.add_cell(content, 1, 1, false, HashMap::new())?;
+        }
+    }
+    builder.finish(HashMap::new())
+}
+
+// CSV 렌더링. 병합 셀의 내용은 시작 위치에만 쓰고 나머지는 비워 둔다
+// 셀 안의 줄바꿈과 공백은 따옴표 필드로 그대로 보존한다. 줄 끝은 RFC 4180에 따라 CRLF이고, 구분자나 따옴표, 앞뒤 공백이 있는 필드만 따옴표로 감싼다
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    for r in 0..table.row_count() {
+        for c in 0..table.col_count() {
+            if c > 0 {
+                out.write_char(',')?;
+            }
+            if let Some(cell) = table.cell(r, c) {
+                write_field(cell.content, out)?;
+            }
+        }
+        out.write_str("\r\n")?;
+    }
+    Ok(())
+}
+
+fn write_field(text: &str, out: &mut impl fmt::Write) -> fmt::Result {
+    let needs_quotes = text.contains([',', '"', '\r', '\n']) || text.starts_with(' ') || text.ends_with(' ');
+    if needs_quotes {
+        write!(out, "\"{}\"", text.replace('"', "\"\""))
+    } else {
+        out.write_str(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 따옴표 필드, 이스케이프, CRLF, 빈 줄, BOM
+    #[test]
+    fn test_parse_csv() {
+        let table = Table::from_csv("\u{FEFF}name,note\r\n\"Kim, A\",\"say \"\"hi\"\"\"\r\n\r\nLee,\"두\n줄\"\n,\n").unwrap();
+        assert_eq!(table.row_count(), 4);
+        assert_eq!(table.cell(1, 0).unwrap().content, "Kim, A");
+        assert_eq!(table.cell(1, 1).unwrap().content, "say \"hi\"");
+        assert_eq!(table.cell(2, 1).unwrap().content, "두\n줄");
+        assert_eq!(table.cell(3, 1).unwrap().content, "");
+        assert!(!table.cell(0, 0).unwrap().is_header);
+
+        assert!(Table::from_csv("a,\"b").is_err());
+        assert!(Table::from_csv("\n\n").is_err());
+    }
+
+    // 병합 셀은 시작 위치에만, 필요한 필드만 따옴표
+    #[test]
+    fn test_render_csv() {
+        let table = Table::from_html(r#"<table>
+            <tr><th colspan="2">a, b</th></tr>
+            <tr><td>say "hi"</td><td> x</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_csv(), "\"a, b\",\r\n\"say \"\"hi\"\"\",x\r\n");
+
+        let csv = "x,\"y,z\"\r\n1,2\r\n";
+        assert_eq!(Table::from_csv(csv).unwrap().to_csv(), csv);
+    }
+
+    // 여러 줄 셀과 연속 공백은 왕복해도 바뀌지 않는다
+    #[test]
+    fn test_roundtrip_preserves_content() {
+        let csv = "\"첫 줄\n둘째 줄\",a  b\r\n\"x\r\ny\", lead\r\n";
+        let table = Table::from_csv(csv).unwrap();
+        assert_eq!(table.cell(0, 0).unwrap().content, "첫 줄\n둘째 줄");
+        assert_eq!(table.cell(0, 1).unwrap().content, "a  b");
+        let output = table.to_csv();
+        assert_eq!(output, "\"첫 줄\n둘째 줄\",a  b\r\n\"x\r\ny\",\" lead\"\r\n");
+        let reparsed = Table::from_csv(&output).unwrap();
+        for (cell, other) in table.cells().iter().zip(reparsed.cells()) {
+            assert_eq!(cell.content, other.content);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use scraper::{Html, Selector};
+
+use crate::html;
+use crate::table::Table;
+
+// 내용을 태그로 해석하지 않는 요소
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title", "xmp", "iframe", "noembed", "noframes"];
+
+// HTML 문서 안에서 선택자와 일치하는 테이블만 rewrite 결과로 바꾸고, 나머지 부분은 바이트 단위로 그대로 둔다
+// 선택은 from_html_with_selector와 같다: 일치한 <table>, 또는 일치한 요소 안의 첫 번째 <table>
+// 선택된 테이블 안에 선택된 테이블이 또 있으면 바깥 테이블만 바꾼다
+pub fn rewrite_tables(html: &str, selector: &str, mut rewrite: impl FnMut(&Table) -> Table) -> Result<String, String> {
+    let document = Html::parse_document(html);
+    let table_selector = Selector::parse("table").map_err(|e| format!("Failed to parse table selector: {}", e))?;
+    let selector = Selector::parse(selector).map_err(|_| format!("Invalid selector \"{}\"", selector))?;
+
+    // 파서가 만든 <table> 요소와 원문에서 찾은 <table> 태그 위치를 문서 순서대로 짝짓는다
+    let tables: Vec<_> = document.select(&table_selector).collect();
+    let ranges = table_ranges(html);
+    if tables.len() != ranges.len() {
+        return Err("Could not locate the tables in the source document".to_string());
+    }
+    let index_of: HashMap<_, _> = tables.iter().enumerate().map(|(index, table)| (table.id(), index)).collect();
+
+    let mut selected: Vec<usize> = document
+        .select(&selector)
+        .filter_map(|element| {
+            if element.value().name() == "table" {
+                Some(element)
+            } else {
+                element.select(&table_selector).next()
+            }
+        })
+        .filter_map(|table| index_of.get(&table.id()).copied())
+        .collect();
+    selected.sort_unstable();
+    selected.dedup();
+    if selected.is_empty() {
+        return Err("No <table> element found".to_string());
+    }
+
+    let mut output = String::with_capacity(html.len());
+    let mut position = 0;
+    for index in selected {
+        let range = ranges[index].clone();
+        if range.start < position {
+            continue;
+        }
+        let table = html::read_table(tables[index], None)?;
+        output.push_str(&html[position..range.start]);
+        output.push_str(&rewrite(&table).to_html());
+        position = range.end;
+    }
+    output.push_str(&html[position..]);
+    Ok(output)
+}
+
+// 원문에서 각 <table> 시작 태그부터 짝이 맞는 </table> 끝까지의 바이트 범위 (시작 태그 순서)
+// 주석과 script/style 같은 원시 텍스트 요소 안은 건너뛰고, 닫히지 않은 테이블은 문서 끝까지로 본다
+fn table_ranges(html: &str) -> Vec<Range<usize>> {
+    let bytes = html.as_bytes();
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    let mut i = 0;
+
+    while let Some(offset) = html[i..].find('<') {
+        let start = i + offset;
+        let rest = &html[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            i = comment.find("-->").map_or(html.len(), |end| start + 4 + end + 3);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            i = rest.find('>').map_or(html.len(), |end| start + end + 1);
+            continue;
+        }
+
+        let closing = rest.starts_with("</");
+        let name_start = start + if closing { 2 } else { 1 };
+        let name_len = bytes[name_start..].iter().take_while(|b| b.is_ascii_alphanumeric()).count();
+        if name_len == 0 || !bytes[name_start].is_ascii_alphabetic() {
+            i = start + 1;
+            continue;
+        }
+        let name = html[name_start..name_start + name_len].to_ascii_lowercase();
+        let end = tag_end(html, name_start + name_len);
+
+        if closing {
+            if name == "table"
+                && let Some(index) = open.pop()
+            {
+                ranges[index].end = end;
+            }
+            i = end;
+        } else if name == "table" {
+            open.push(ranges.len());
+            ranges.push(start..html.len());
+            i = end;
+        } else if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            i = raw_text_end(html, end, &name);
+        } else {
+            i = end;
+        }
+    }
+    ranges
+}
+
+// 태그 이름 뒤에서 따옴표 안의 > 를 건너뛰고 태그가 끝나는 위치 다음
+fn tag_end(html: &str, from: usize) -> usize {
+    let mut quote = None;
+    for (offset, ch) in html[from..].char_indices() {
+        match (quote, ch) {
+            (Some(q), _) if ch == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, '>') => return from + offset + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+// 원시 텍스트 요소의 닫는 태그가 끝나는 위치
+fn raw_text_end(html: &str, from: usize, name: &str) -> usize {
+    let closing = format!("</{}", name);
+    let lower = html[from..].to_ascii_lowercase();
+    match lower.find(&closing) {
+        Some(offset) => tag_end(html, from + offset + closing.len()),
+        None => html.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><head><title>보고서 <table></title>
+<script>var s = "<table>";</script></head>
+<body>
+  <p class='intro'>앞 문단</p>
+  <!-- <table><tr><td>주석</td></tr></table> -->
+  <TABLE id="a"><tr><td>1</td><td>2</td></tr></TABLE>
+  <div id="b"><table data-x="a>b"><tr><td>3</td></tr><tr><td>4</td></tr></table></div>
+</body></html>
+"#;
+
+    // 원문의 테이블 범위는 주석, script, title 안의 <table>과 따옴표 안의 > 를 무시해야 함
+    #[test]
+    fn test_table_ranges() {
+        let ranges = table_ranges(PAGE);
+        assert_eq!(ranges.len(), 2);
+        assert!(PAGE[ranges[0].clone()].starts_with(r#"<TABLE id="a">"#));
+        assert!(PAGE[ranges[0].clone()].ends_with("</TABLE>"));
+        assert!(PAGE[ranges[1].clone()].starts_with(r#"<table data-x="a>b">"#));
+
+        // 중첩과 닫히지 않은 테이블
+        let html = "<table><tr><td><table></table></td></tr></table><table><tr><td>x";
+        assert_eq!(table_ranges(html), vec![0..48, 15..30, 48..html.len()]);
+    }
+
+    // 선택된 테이블만 바뀌고 나머지는 그대로 남아야 함
+    #[test]
+    fn test_rewrite_tables() {
+        let rewritten = rewrite_tables(PAGE, "#b", Table::transpose).unwrap();
+        let expected = PAGE.replace(
+            r#"<table data-x="a>b"><tr><td>3</td></tr><tr><td>4</td></tr></table>"#,
+            r#"<table data-x="a>b"><tr><td>3</td><td>4</td></tr></table>"#,
+        );
+        assert_eq!(rewritten, expected);
+
+        let rewritten = rewrite_tables(PAGE, "table", Table::transpose).unwrap();
+        assert!(rewritten.contains(r#"<table id="a"><tr><td>1</td></tr><tr><td>2</td></tr></table>"#));
+        assert!(rewritten.contains("<title>보고서 <table></title>"));
+        assert!(rewritten.contains("<!-- <table><tr><td>주석</td></tr></table> -->"));
+
+        assert!(rewrite_tables(PAGE, "ul", Table::transpose).is_err());
+    }
+
+    // 선택된 테이블 안의 테이블은 바깥 테이블과 함께 바뀐다 (중첩 테이블은 셀 텍스트로 남는다)
+    #[test]
+    fn test_rewrite_nested() {
+        let html = "<p>x</p><table><tr><td>a<table><tr><td>b</td><td>b</td></tr></table></td><td>c</td></tr></table>";
+        let rewritten = rewrite_tables(html, "table", Table::transpose).unwrap();
+        assert_eq!(rewritten, "<p>x</p><table><tr><td>abb</td></tr><tr><td>c</td></tr></table>");
+    }
+}
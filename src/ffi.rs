@@ -0,0 +1,230 @@
+use std::any::Any;
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use crate::{Format, Table, TransposeOptions};
+
+// C에서 호출하는 API. 헤더는 cbindgen이 include/html_transpose.h로 만든다
+// 모든 함수는 성공하면 0을 돌려주고 *out에, 실패하면 -1을 돌려주고 *err에 문자열을 넣는다
+// 돌려준 문자열은 html_transpose_string_free로 해제해야 한다 (C의 free로 해제하면 안 된다)
+
+pub const HTML_TRANSPOSE_OK: c_int = 0;
+pub const HTML_TRANSPOSE_ERROR: c_int = -1;
+
+// TransposeOptions의 C 표현. id_prefix가 NULL이면 접두사를 붙이지 않는다
+#[repr(C)]
+pub struct HtmlTransposeOptions {
+    pub swap_scope: bool,
+    pub infer_scope: bool,
+    pub id_prefix: *const c_char,
+    pub generate_headers: bool,
+    pub swap_axis_styles: bool,
+}
+
+// Rust API와 같은 기본값
+#[unsafe(no_mangle)]
+pub extern "C" fn html_transpose_options_default() -> HtmlTransposeOptions {
+    let options = TransposeOptions::default();
+    HtmlTransposeOptions {
+        swap_scope: options.swap_scope,
+        infer_scope: options.infer_scope,
+        id_prefix: ptr::null(),
+        generate_headers: options.generate_headers,
+        swap_axis_styles: options.swap_axis_styles,
+    }
+}
+
+// 첫 번째 <table>을 전치한 HTML
+// 안전 조건: html은 NUL로 끝나는 UTF-8 문자열, out과 err는 쓸 수 있는 포인터여야 한다
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_transpose_transpose(html: *const c_char, out: *mut *mut c_char, err: *mut *mut c_char) -> c_int {
+    unsafe { respond(out, err, || crate::transpose(read_str(html, "html")?)) }
+}
+
+// 안전 조건: html_transpose_transpose의 조건에 더해 options는 NULL이거나 올바른 HtmlTransposeOptions를 가리켜야 한다
+// NULL이면 기본 옵션을 쓴다
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_transpose_transpose_with_options(
+    html: *const c_char,
+    options: *const HtmlTransposeOptions,
+    out: *mut *mut c_char,
+    err: *mut *mut c_char,
+) -> c_int {
+    unsafe {
+        respond(out, err, || {
+            let html = read_str(html, "html")?;
+            let options = match options.as_ref() {
+                Some(options) => TransposeOptions {
+                    swap_scope: options.swap_scope,
+                    infer_scope: options.infer_scope,
+                    id_prefix: if options.id_prefix.is_null() {
+                        None
+                    } else {
+                        Some(read_str(options.id_prefix, "id_prefix")?.to_string())
+                    },
+                    generate_headers: options.generate_headers,
+                    swap_axis_styles: options.swap_axis_styles,
+                },
+                None => TransposeOptions::default(),
+            };
+            crate::transpose_with_options(html, &options)
+        })
+    }
+}
+
+// 포맷 이름(html, markdown, csv 등 명령줄의 --from/--to 값)으로 테이블을 다른 포맷으로 바꾼다
+// 안전 조건: text, from, to는 NUL로 끝나는 UTF-8 문자열, out과 err는 쓸 수 있는 포인터여야 한다
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_transpose_convert(
+    text: *const c_char,
+    from: *const c_char,
+    to: *const c_char,
+    out: *mut *mut c_char,
+    err: *mut *mut c_char,
+) -> c_int {
+    unsafe {
+        respond(out, err, || {
+            let to: Format = read_str(to, "to")?.parse()?;
+            let table = Table::parse(read_str(text, "text")?, read_str(from, "from")?.parse()?)?;
+            Ok(table.render(to))
+        })
+    }
+}
+
+// 이 라이브러리가 돌려준 문자열을 해제한다. NULL이면 아무것도 하지 않는다
+// 안전 조건: s는 NULL이거나 이 라이브러리가 돌려준 뒤 아직 해제하지 않은 문자열이어야 한다
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn html_transpose_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+// 라이브러리 버전 (정적 문자열이므로 해제하지 않는다)
+#[unsafe(no_mangle)]
+pub extern "C" fn html_transpose_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+unsafe fn read_str<'a>(s: *const c_char, name: &str) -> Result<&'a str, String> {
+    if s.is_null() {
+        return Err(format!("{} is NULL", name));
+    }
+    unsafe { CStr::from_ptr(s) }.to_str().map_err(|_| format!("{} is not valid UTF-8", name))
+}
+
+// 결과를 out 또는 err에 넣는다. 쓰지 않는 쪽은 NULL로 채운다
+// 패닉이 C ABI를 넘어가면 호출한 프로그램이 중단되므로 여기서 잡아 오류로 돌려준다
+unsafe fn respond(out: *mut *mut c_char, err: *mut *mut c_char, run: impl FnOnce() -> Result<String, String>) -> c_int {
+    if out.is_null() || err.is_null() {
+        return HTML_TRANSPOSE_ERROR;
+    }
+    let result = panic::catch_unwind(AssertUnwindSafe(run)).unwrap_or_else(|payload| Err(panic_message(payload)));
+    let result = result.and_then(|text| CString::new(text).map_err(|_| "Output contains a NUL character".to_string()));
+    unsafe {
+        match result {
+            Ok(text) => {
+                *out = text.into_raw();
+                *err = ptr::null_mut();
+                HTML_TRANSPOSE_OK
+            }
+            Err(message) => {
+                *out = ptr::null_mut();
+                *err = CString::new(message).unwrap_or_default().into_raw();
+                HTML_TRANSPOSE_ERROR
+            }
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic");
+    format!("Internal error: {}", message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // C에서처럼 호출하고 결과 문자열을 꺼낸 뒤 해제한다
+    fn call(run: impl FnOnce(*mut *mut c_char, *mut *mut c_char) -> c_int) -> Result<String, String> {
+        let mut out = ptr::null_mut();
+        let mut err = ptr::null_mut();
+        let status = run(&mut out, &mut err);
+        let taken = |s: *mut c_char| {
+            let text = unsafe { CStr::from_ptr(s) }.to_str().unwrap().to_string();
+            unsafe { html_transpose_string_free(s) };
+            text
+        };
+        if status == HTML_TRANSPOSE_OK {
+            assert!(err.is_null());
+            Ok(taken(out))
+        } else {
+            assert!(out.is_null());
+            Err(taken(err))
+        }
+    }
+
+    // 전치, 옵션, 포맷 변환
+    #[test]
+    fn test_ffi_calls() {
+        let html = c"<table><tr><th>H</th><td>1</td></tr></table>";
+        assert_eq!(
+            call(|out, err| unsafe { html_transpose_transpose(html.as_ptr(), out, err) }),
+            crate::transpose(html.to_str().unwrap())
+        );
+
+        let mut options = html_transpose_options_default();
+        options.infer_scope = true;
+        options.id_prefix = c"t-".as_ptr();
+        let expected = crate::transpose_with_options(html.to_str().unwrap(), &TransposeOptions {
+            infer_scope: true,
+            id_prefix: Some("t-".to_string()),
+            ..TransposeOptions::default()
+        });
+        assert_eq!(call(|out, err| unsafe { html_transpose_transpose_with_options(html.as_ptr(), &options, out, err) }), expected);
+
+        let converted = call(|out, err| unsafe {
+            html_transpose_convert(c"| a | b |".as_ptr(), c"org".as_ptr(), c"html".as_ptr(), out, err)
+        });
+        assert_eq!(converted.unwrap(), "<table><tr><td>a</td><td>b</td></tr></table>");
+    }
+
+    // 오류 메시지와 잘못된 포인터
+    #[test]
+    fn test_ffi_errors() {
+        let error = call(|out, err| unsafe { html_transpose_transpose(c"<p>x</p>".as_ptr(), out, err) });
+        assert_eq!(error.unwrap_err(), "No <table> element found");
+        let error = call(|out, err| unsafe { html_transpose_transpose(ptr::null(), out, err) });
+        assert_eq!(error.unwrap_err(), "html is NULL");
+        let error = call(|out, err| unsafe { html_transpose_transpose(c"\xff".as_ptr(), out, err) });
+        assert_eq!(error.unwrap_err(), "html is not valid UTF-8");
+
+        let mut out = ptr::null_mut();
+        let status = unsafe { html_transpose_transpose(c"<table></table>".as_ptr(), &mut out, ptr::null_mut()) };
+        assert_eq!(status, HTML_TRANSPOSE_ERROR);
+        unsafe { html_transpose_string_free(ptr::null_mut()) };
+    }
+
+    // 저장소의 헤더가 cbindgen이 만든 헤더와 같아야 한다 (다르면 README의 cbindgen 명령으로 다시 만든다)
+    #[test]
+    fn test_header_is_current() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/html_transpose.h"));
+        let committed = include_str!("../include/html_transpose.h");
+        assert!(generated == committed, "include/html_transpose.h is out of date");
+    }
+
+    // 라이브러리 안의 패닉은 C 쪽으로 넘어가지 않고 오류 메시지가 된다
+    #[test]
+    fn test_ffi_panic() {
+        let error = call(|out, err| unsafe { respond(out, err, || panic!("span overflow")) });
+        assert_eq!(error.unwrap_err(), "Internal error: span overflow");
+        let error = call(|out, err| unsafe { respond(out, err, || panic!("row {}", 3)) });
+        assert_eq!(error.unwrap_err(), "Internal error: row 3");
+    }
+}
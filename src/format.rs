@@ -0,0 +1,243 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use crate::table::Table;
+use crate::{asciidoc, csv, html, markdown, mediawiki, org, rst};
+
+// 지원하는 테이블 포맷
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Html,
+    MediaWiki,
+    Org,
+    Rst,
+    AsciiDoc,
+    Csv,
+    Markdown,
+}
+
+impl Format {
+    pub const ALL: [Format; 7] = [
+        Format::Html,
+        Format::MediaWiki,
+        Format::Org,
+        Format::Rst,
+        Format::AsciiDoc,
+        Format::Csv,
+        Format::Markdown,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::MediaWiki => "mediawiki",
+            Format::Org => "org",
+            Format::Rst => "rst",
+            Format::AsciiDoc => "asciidoc",
+            Format::Csv => "csv",
+            Format::Markdown => "markdown",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::MediaWiki => "wiki",
+            Format::Org => "org",
+            Format::Rst => "rst",
+            Format::AsciiDoc => "adoc",
+            Format::Csv => "csv",
+            Format::Markdown => "md",
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Format> {
+        match extension.to_ascii_lowercase().as_str() {
+            "html" | "htm" | "xhtml" => Some(Format::Html),
+            "wiki" | "mediawiki" => Some(Format::MediaWiki),
+            "org" => Some(Format::Org),
+            "rst" => Some(Format::Rst),
+            "adoc" | "asciidoc" => Some(Format::AsciiDoc),
+            "csv" => Some(Format::Csv),
+            "md" | "markdown" => Some(Format::Markdown),
+            _ => None,
+        }
+    }
+
+    // HTTP Content-Type 값 (텍스트 포맷은 모두 UTF-8)
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Format::Html => "text/html; charset=utf-8",
+            Format::Csv => "text/csv; charset=utf-8",
+            Format::Markdown => "text/markdown; charset=utf-8",
+            Format::MediaWiki | Format::Org | Format::Rst | Format::AsciiDoc => "text/plain; charset=utf-8",
+        }
+    }
+
+    // Content-Type 헤더에서 포맷을 고른다. 매개변수(charset 등)는 무시하고, text/plain처럼 포맷을 알 수 없으면 None
+    pub fn from_media_type(media_type: &str) -> Option<Format> {
+        let essence = media_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+        match essence.as_str() {
+            "text/html" | "application/xhtml+xml" => Some(Format::Html),
+            "text/csv" => Some(Format::Csv),
+            "text/markdown" | "text/x-markdown" => Some(Format::Markdown),
+            "text/x-rst" => Some(Format::Rst),
+            "text/x-org" => Some(Format::Org),
+            "text/asciidoc" => Some(Format::AsciiDoc),
+            _ => None,
+        }
+    }
+
+    // 입력으로 읽을 수 있는 포맷인지
+    pub fn is_readable(&self) -> bool {
+        matches!(self, Format::Html | Format::MediaWiki | Format::Org | Format::Csv | Format::Markdown)
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "html" | "htm" => Ok(Format::Html),
+            "mediawiki" | "wiki" => Ok(Format::MediaWiki),
+            "org" => Ok(Format::Org),
+            "rst" => Ok(Format::Rst),
+            "asciidoc" | "adoc" => Ok(Format::AsciiDoc),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            _ => Err(format!(
+                "Unknown format \"{}\" (expected one of: {})",
+                s,
+                Format::ALL.map(|format| format.name()).join(", ")
+            )),
+        }
+    }
+}
+
+fn parse_format(text: &str, format: Format, max_cells: Option<usize>) -> Result<Table, String> {
+    match format {
+        Format::Html => html::parse(text, max_cells),
+        Format::MediaWiki => mediawiki::parse(text, max_cells),
+        Format::Org => org::parse(text, max_cells),
+        Format::Csv => csv::parse(text, max_cells),
+        Format::Markdown => markdown::parse(text, max_cells),
+        Format::Rst | Format::AsciiDoc => Err(format!("Reading {} tables is not supported", format)),
+    }
+}
+
+impl Table {
+    pub fn parse(text: &str, format: Format) -> Result<Table, String> {
+        parse_format(text, format, None)
+    }
+
+    // parse와 같지만 행 수 x 열 수가 max_cells를 넘는 테이블은 메모리를 할당하기 전에 오류로 처리한다
+    // 신뢰할 수 없는 입력(작은 입력의 큰 병합 셀)을 받는 서버 등에서 쓴다
+    pub fn parse_with_limit(text: &str, format: Format, max_cells: usize) -> Result<Table, String> {
+        parse_format(text, format, Some(max_cells))
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        let mut output = String::new();
+        // String에 쓰는 것은 실패하지 않는다
+        let _ = self.write_formatted(format, &mut output);
+        output
+    }
+
+    // 결과 전체를 문자열로 만들지 않고 writer에 바로 쓴다 (큰 테이블을 파일이나 소켓으로 보낼 때)
+    // 작은 조각으로 여러 번 쓰므로 파일이나 소켓은 BufWriter로 감싸서 넘기는 것이 좋다
+    pub fn write_to(&self, format: Format, out: &mut impl io::Write) -> io::Result<()> {
+        let mut adapter = IoAdapter { inner: out, error: None };
+        self.write_formatted(format, &mut adapter)
+            .map_err(|_| adapter.error.unwrap_or_else(|| io::Error::other("formatter error")))
+    }
+
+    fn write_formatted(&self, format: Format, out: &mut impl fmt::Write) -> fmt::Result {
+        match format {
+            Format::Html => html::write(self, out),
+            Format::MediaWiki => mediawiki::write(self, out),
+            Format::Org => org::write(self, out),
+            Format::Rst => rst::write(self, out),
+            Format::AsciiDoc => asciidoc::write(self, out),
+            Format::Csv => csv::write(self, out),
+            Format::Markdown => markdown::write(self, out),
+        }
+    }
+}
+
+// 포맷별 출력 함수(fmt::Write)를 io::Write에 연결한다. fmt::Error에는 원인이 없으므로 io 오류를 따로 보관한다
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Format, Table};
+
+    // 이름/확장자 파싱과 포맷별 입출력 연결
+    #[test]
+    fn test_format_names_and_dispatch() {
+        assert_eq!("WIKI".parse::<Format>(), Ok(Format::MediaWiki));
+        assert_eq!(Format::from_extension("htm"), Some(Format::Html));
+        assert_eq!("md".parse::<Format>(), Ok(Format::Markdown));
+        assert!("xlsx".parse::<Format>().unwrap_err().contains("html, mediawiki, org, rst, asciidoc, csv, markdown"));
+        assert_eq!(Format::from_media_type("text/CSV; charset=utf-8"), Some(Format::Csv));
+        assert_eq!(Format::from_media_type("text/plain"), None);
+
+        let table = Table::parse("| a | b |", Format::Org).unwrap();
+        assert_eq!(table.render(Format::Html), "<table><tr><td>a</td><td>b</td></tr></table>");
+        assert!(Table::parse("+---+", Format::Rst).is_err());
+    }
+
+    // writer 출력은 render와 같아야 하고, 쓰기 오류는 그대로 전달해야 함
+    #[test]
+    fn test_write_to() {
+        let table = Table::from_html(r#"<table><tr><th colspan="2">머리</th></tr><tr><td>1</td><td>2</td></tr></table>"#).unwrap();
+        for format in Format::ALL {
+            let mut output = Vec::new();
+            table.write_to(format, &mut output).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), table.render(format));
+        }
+
+        struct Full;
+        impl std::io::Write for Full {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::StorageFull.into())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+        let error = table.write_to(Format::Html, &mut Full).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::StorageFull);
+    }
+
+    // 셀 내용과 속성 값의 특수 문자는 writer에 쓰면서 이스케이프한다
+    #[test]
+    fn test_write_to_escapes() {
+        let table = Table::from_html(r#"<table class="a&quot;b"><tr><td title="x &lt; &amp; y">&lt;b&gt; 'Tom' &amp; "Jerry" 끝</td></tr></table>"#).unwrap();
+        let mut output = Vec::new();
+        table.write_to(Format::Html, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"<table class="a&quot;b"><tr><td title="x &lt; &amp; y">&lt;b&gt; &apos;Tom&apos; &amp; &quot;Jerry&quot; 끝</td></tr></table>"#
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use scraper::{ElementRef, Html, Selector};
+
+use crate::table::{MAX_COLSPAN, MAX_ROWSPAN, Table, TableBuilder, span_value};
+
+pub(crate) fn parse(html: &str, max_cells: Option<usize>) -> Result<Table, String> {
+    parse_with_selector(html, "table", max_cells)
+}
+
+// 선택자와 일치하는 첫 번째 <table>, 또는 일치한 요소 안의 첫 번째 <table>을 읽는다
+pub(crate) fn parse_with_selector(html: &str, selector: &str, max_cells: Option<usize>) -> Result<Table, String> {
+    let document = Html::parse_document(html);
+
+    let table_selector = Selector::parse("table").map_err(|e| format!("Failed to parse table selector: {}", e))?;
+    let selector = Selector::parse(selector).map_err(|_| format!("Invalid selector \"{}\"", selector))?;
+    let root = document.select(&selector)
+        .find_map(|element| {
+            if element.value().name() == "table" {
+                Some(element)
+            } else {
+                element.select(&table_selector).next()
+            }
+        })
+        .ok_or("No <table> element found")?;
+
+    read_table(root, max_cells)
+}
+
+// <table> 요소 하나를 테이블 모델로 읽는다. max_cells는 그리드 위치 수 상한
+pub(crate) fn read_table(root: ElementRef<'_>, max_cells: Option<usize>) -> Result<Table, String> {
+    let mut table_attributes: HashMap<String, String> = HashMap::new();
+    for (attr_name, attr_value) in root.value().attrs() {
+        table_attributes.insert(attr_name.to_string(), attr_value.to_string());
+    }
+
+    let tr_selector = Selector::parse("tr").map_err(|e| format!("Failed to parse tr selector: {}", e))?;
+
+    let mut builder = TableBuilder::with_limit(max_cells);
+
+    // 셀 안에 중첩된 테이블의 행과 셀은 이 테이블에 넣지 않는다
+    let own_rows = root.select(&tr_selector).filter(|row| {
+        row.ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|ancestor| ancestor.value().name() == "table")
+            .is_some_and(|table| table.id() == root.id())
+    });
+    for row in own_rows {
+        builder.start_row();
+
+        let cells = row.children().filter_map(ElementRef::wrap).filter(|cell| matches!(cell.value().name(), "td" | "th"));
+        for cell in cells {
+            // 셀 타입 확인 (th인지 td인지)
+            let is_header = cell.value().name() == "th";
+
+            let rowspan = span_value(cell.value().attr("rowspan"), MAX_ROWSPAN);
+            let colspan = span_value(cell.value().attr("colspan"), MAX_COLSPAN);
+
+            let content = cell.text().collect::<String>().trim().to_string();
+
+            let mut attributes: HashMap<String, String> = HashMap::new();
+            for (attr_name, attr_value) in cell.value().attrs() {
+                let name = attr_name.to_string();
+                if name != "rowspan" && name != "colspan" {
+                    attributes.insert(name, attr_value.to_string());
+                }
+            }
+
+            builder.add_cell(content, rowspan, colspan, is_header, attributes)?;
+        }
+    }
+
+    builder.finish(table_attributes)
+}
+
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    out.write_str("<table")?;
+    for (attr_name, attr_value) in table.table_attributes() {
+        write_attribute(attr_name, attr_value, out)?;
+    }
+    out.write_char('>')?;
+
+    for r in 0..table.row_count() {
+        out.write_str("<tr>")?;
+
+        let mut c = 0;
+        while c < table.col_count() {
+            if let Some(cell) = table.cell(r, c) {
+                // is_header에 따라 <th> 또는 <td> 사용
+                let tag = if cell.is_header { "th" } else { "td" };
+                write!(out, "<{}", tag)?;
+                if cell.rowspan > 1 {
+                    write!(out, " rowspan=\"{}\"", cell.rowspan)?;
+                }
+                if cell.colspan > 1 {
+                    write!(out, " colspan=\"{}\"", cell.colspan)?;
+                }
+                for (attr_name, attr_value) in cell.attributes() {
+                    write_attribute(attr_name, attr_value, out)?;
+                }
+                out.write_char('>')?;
+                write_escaped(cell.content, html_entity, out)?;
+                write!(out, "</{}>", tag)?;
+
+                c += cell.colspan;
+            } else if table.is_covered(r, c) {
+                // 병합된 셀이 차지하는 위치는 출력하지 않음
+                c += 1;
+            } else {
+                // 빈 셀은 기본적으로 <td> 사용
+                out.write_str("<td></td>")?;
+                c += 1;
+            }
+        }
+
+        out.write_str("</tr>")?;
+    }
+
+    out.write_str("</table>")
+}
+
+// ` name="value"` 형태의 속성 하나
+fn write_attribute(name: &str, value: &str, out: &mut impl fmt::Write) -> fmt::Result {
+    write!(out, " {}=\"", name)?;
+    write_escaped(value, attr_entity, out)?;
+    out.write_char('"')
+}
+
+// 문자열을 훑으면서 이스케이프할 문자가 없는 구간은 그대로, 특수 문자는 entity가 돌려준 문자열로 쓴다
+// 셀마다 이스케이프한 문자열을 새로 만들지 않는다
+pub(crate) fn write_escaped(text: &str, entity: fn(char) -> Option<&'static str>, out: &mut impl fmt::Write) -> fmt::Result {
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        if let Some(escaped) = entity(c) {
+            out.write_str(&text[start..index])?;
+            out.write_str(escaped)?;
+            start = index + c.len_utf8();
+        }
+    }
+    out.write_str(&text[start..])
+}
+
+// HTML 특수 문자
+fn html_entity(c: char) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '<' => Some("&lt;"),
+        '>' => Some("&gt;"),
+        '"' => Some("&quot;"),
+        '\'' => Some("&apos;"),
+        _ => None,
+    }
+}
+
+// HTML attribute 값에서 이스케이프할 문자
+pub(crate) fn attr_entity(c: char) -> Option<&'static str> {
+    match c {
+        '&' => Some("&amp;"),
+        '"' => Some("&quot;"),
+        '<' => Some("&lt;"),
+        _ => None,
+    }
+}
@@ -1,244 +1,75 @@
-use std::collections::HashMap;
-
-use scraper::{Html, Selector};
-
-// 병합된 셀 정보를 저장하는 구조체
-#[derive(Debug, Clone)]
-struct MergedCell {
-    rowspan: usize,
-    colspan: usize,
-    content: String,
-    attributes: HashMap<String, String>, // rowspan, colspan을 제외한 다른 속성들
-    is_header: bool, // 헤더 셀(th)인지 여부
-}
+use std::io::Write;
+
+mod accessibility;
+mod asciidoc;
+mod axis;
+mod css;
+mod csv;
+mod document;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod format;
+mod html;
+mod markdown;
+mod mediawiki;
+mod merge;
+mod org;
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "python")]
+mod python;
+mod records;
+mod rotate;
+mod rst;
+mod stream;
+mod table;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use accessibility::HeaderIssue;
+pub use css::{Declaration, parse_style, serialize_style};
+pub use document::rewrite_tables;
+pub use format::Format;
+pub use merge::{AutoMergeOptions, UnmergeFill};
+#[cfg(feature = "parallel")]
+pub use parallel::{transpose_many, transpose_tables};
+pub use records::Records;
+pub use rotate::Rotation;
+pub use table::{Cell, Table, TransposeOptions};
 
 pub fn transpose(html: &str) -> Result<String, String> {
-    let document = Html::parse_document(html);
-    
-    let table_selector = Selector::parse("table").map_err(|e| format!("Failed to parse table selector: {}", e))?;
-    let root = document.select(&table_selector).next()
-        .ok_or("No <table> element found")?;
-    
-    let mut table_attributes: HashMap<String, String> = HashMap::new();
-    for (attr_name, attr_value) in root.value().attrs() {
-        table_attributes.insert(attr_name.to_string(), attr_value.to_string());
-    }
-
-    let tr_selector = Selector::parse("tr").map_err(|e| format!("Failed to parse tr selector: {}", e))?;
-    let td_selector = Selector::parse("td, th").map_err(|e| format!("Failed to parse td/th selector: {}", e))?;
-
-    let mut grid: Vec<Vec<Option<String>>> = Vec::new();
-    let mut merged_cells: HashMap<(usize, usize), MergedCell> = HashMap::new();
-    let mut cell_attributes: HashMap<(usize, usize), HashMap<String, String>> = HashMap::new();
-    let mut occupied_positions: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
-    let mut header_cells: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new(); // 헤더 셀 위치 추적
-    
-    for (row_idx, row) in root.select(&tr_selector).enumerate() {
-        if row_idx >= grid.len() {
-            grid.push(Vec::new());
-        }
-        
-        let mut col_idx = 0;
-        
-        while col_idx < grid[row_idx].len() && grid[row_idx][col_idx].is_some() {
-            col_idx += 1;
-        }
-        
-        for cell in row.select(&td_selector) {
-            while col_idx < grid[row_idx].len() && grid[row_idx][col_idx].is_some() {
-                col_idx += 1;
-            }
-            
-            // 셀 타입 확인 (th인지 td인지)
-            let is_header = cell.value().name() == "th";
-            
-            let rowspan = cell.value().attr("rowspan")
-                .and_then(|s| s.parse::<usize>().ok())
-                .unwrap_or(1);
-            let colspan = cell.value().attr("colspan")
-                .and_then(|s| s.parse::<usize>().ok())
-                .unwrap_or(1);
-
-            let content = cell.text().collect::<String>().trim().to_string();
-            
-            let mut attributes: HashMap<String, String> = HashMap::new();
-            for (attr_name, attr_value) in cell.value().attrs() {
-                let name = attr_name.to_string();
-                if name != "rowspan" && name != "colspan" {
-                    attributes.insert(name, attr_value.to_string());
-                }
-            }
-            
-            let needed_rows = row_idx + rowspan;
-            let needed_cols = col_idx + colspan;
-            
-            while grid.len() < needed_rows {
-                grid.push(Vec::new());
-            }
-            
-            for r in 0..needed_rows {
-                while grid[r].len() < needed_cols {
-                    grid[r].push(None);
-                }
-            }
-            
-            grid[row_idx][col_idx] = Some(content.clone());
-            
-            // 헤더 셀인 경우 위치 저장
-            if is_header {
-                header_cells.insert((row_idx, col_idx));
-            }
-            
-            if !attributes.is_empty() {
-                cell_attributes.insert((row_idx, col_idx), attributes.clone());
-            }
-            
-            if rowspan > 1 || colspan > 1 {
-                merged_cells.insert((row_idx, col_idx), MergedCell {
-                    rowspan,
-                    colspan,
-                    content: content.clone(),
-                    attributes,
-                    is_header,
-                });
-            }
-            
-            for r in 0..rowspan {
-                for c in 0..colspan {
-                    if r == 0 && c == 0 {
-                    } else {
-                        grid[row_idx + r][col_idx + c] = Some("".to_string());
-                        occupied_positions.insert((row_idx + r, col_idx + c));
-                    }
-                }
-            }
-            
-            col_idx += colspan;
-        }
-    }
-    
-    let max_row = grid.len();
-    let max_col = if max_row > 0 { grid[0].len() } else { 0 };
-
-    let transposed_rows = max_col;
-    let transposed_cols = max_row;
-    let mut transposed_grid: Vec<Vec<Option<String>>> = vec![vec![None; transposed_cols]; transposed_rows];
-    let mut transposed_merged_cells: HashMap<(usize, usize), MergedCell> = HashMap::new();
-    let mut transposed_cell_attributes: HashMap<(usize, usize), HashMap<String, String>> = HashMap::new();
-    let mut transposed_occupied_positions: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
-    let mut transposed_header_cells: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new(); // 전치된 헤더 셀 위치
-
-    for r in 0..max_row {
-        for c in 0..max_col {
-            transposed_grid[c][r] = grid[r][c].clone();
-        }
-    }
-
-    for (row, col) in occupied_positions.iter() {
-        transposed_occupied_positions.insert((*col, *row));
-    }
-
-    // 헤더 셀 위치 전치: (row, col) -> (col, row)
-    for (row, col) in header_cells.iter() {
-        transposed_header_cells.insert((*col, *row));
-    }
-
-    for ((row, col), attrs) in cell_attributes.iter() {
-        if !merged_cells.contains_key(&(*row, *col)) {
-            transposed_cell_attributes.insert((*col, *row), attrs.clone());
-        }
-    }
-
-    for ((row, col), merged_cell) in merged_cells.iter() {
-        transposed_merged_cells.insert((*col, *row), MergedCell {
-            rowspan: merged_cell.colspan,
-            colspan: merged_cell.rowspan,
-            content: merged_cell.content.clone(),
-            attributes: merged_cell.attributes.clone(),
-            is_header: merged_cell.is_header,
-        });
-    }
-
-    let mut html_output = String::from("<table");
-    for (attr_name, attr_value) in &table_attributes {
-        html_output.push_str(&format!(" {}=\"{}\"", attr_name, escape_attr_value(attr_value)));
-    }
-    html_output.push('>');
-    
-    for r in 0..transposed_rows {
-        html_output.push_str("<tr>");
-        
-        let mut c = 0;
-        while c < transposed_cols {
-            if let Some(merged_cell) = transposed_merged_cells.get(&(r, c)) {
-                // 병합된 셀: is_header에 따라 <th> 또는 <td> 사용
-                let tag = if merged_cell.is_header { "th" } else { "td" };
-                html_output.push_str(&format!("<{}", tag));
-                if merged_cell.rowspan > 1 {
-                    html_output.push_str(&format!(" rowspan=\"{}\"", merged_cell.rowspan));
-                }
-                if merged_cell.colspan > 1 {
-                    html_output.push_str(&format!(" colspan=\"{}\"", merged_cell.colspan));
-                }
-                for (attr_name, attr_value) in &merged_cell.attributes {
-                    html_output.push_str(&format!(" {}=\"{}\"", attr_name, escape_attr_value(attr_value)));
-                }
-                html_output.push('>');
-                html_output.push_str(&escape_html(&merged_cell.content));
-                html_output.push_str(&format!("</{}>", tag));
-                
-                c += merged_cell.colspan;
-            } else if let Some(Some(content)) = transposed_grid[r].get(c) {
-                if !transposed_occupied_positions.contains(&(r, c)) {
-                    // 일반 셀: transposed_header_cells에 위치가 있으면 <th>, 없으면 <td>
-                    let is_header = transposed_header_cells.contains(&(r, c));
-                    let tag = if is_header { "th" } else { "td" };
-                    html_output.push_str(&format!("<{}", tag));
-                    if let Some(attrs) = transposed_cell_attributes.get(&(r, c)) {
-                        for (attr_name, attr_value) in attrs {
-                            html_output.push_str(&format!(" {}=\"{}\"", attr_name, escape_attr_value(attr_value)));
-                        }
-                    }
-                    html_output.push('>');
-                    html_output.push_str(&escape_html(content));
-                    html_output.push_str(&format!("</{}>", tag));
-                }
-                c += 1;
-            } else {
-                // 빈 셀은 기본적으로 <td> 사용
-                html_output.push_str("<td></td>");
-                c += 1;
-            }
-        }
-        
-        html_output.push_str("</tr>");
-    }
-    
-    html_output.push_str("</table>");
-    
-    Ok(html_output)
+    transpose_with_options(html, &TransposeOptions::default())
 }
 
-// HTML 특수 문자 이스케이프
-fn escape_html(text: &str) -> String {
-    text.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&apos;")
+pub fn transpose_with_options(html: &str, options: &TransposeOptions) -> Result<String, String> {
+    let table = Table::from_html(html)?;
+    Ok(table.transpose_with(options).to_html())
 }
 
-// HTML attribute 값 이스케이프
-fn escape_attr_value(text: &str) -> String {
-    text.replace('&', "&amp;")
-        .replace('"', "&quot;")
-        .replace('<', "&lt;")
+// 전치한 HTML을 문자열로 만들지 않고 writer에 바로 쓴다
+// 다른 포맷이나 옵션이 필요하면 Table::transpose_with와 Table::write_to를 함께 쓴다
+pub fn transpose_to_writer(html: &str, out: &mut impl Write) -> Result<(), String> {
+    let table = Table::from_html(html)?;
+    table
+        .transpose()
+        .write_to(Format::Html, out)
+        .map_err(|e| format!("Failed to write output: {}", e))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // writer로 쓴 결과는 transpose와 같아야 함
+    #[test]
+    fn test_transpose_to_writer() {
+        let input = "<table><tr><td>A</td><td>B</td></tr></table>";
+        let mut output = Vec::new();
+        transpose_to_writer(input, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), transpose(input).unwrap());
+        assert!(transpose_to_writer("<p>x</p>", &mut Vec::new()).is_err());
+    }
+
     // 간단한 2x2 테이블 테스트
     #[test]
     fn test_simple_2x2_table() {
@@ -351,6 +182,17 @@ mod tests {
         assert_eq!(result, "<table></table>");
     }
 
+    // 행마다 셀 수가 다른 테이블 테스트 (짧은 행은 빈 셀로 채워짐)
+    #[test]
+    fn test_ragged_rows() {
+        let input = r#"<table>
+            <tr><td>A</td><td>B</td></tr>
+            <tr><td>C</td></tr>
+        </table>"#;
+        let result = transpose(input).unwrap();
+        assert_eq!(result, "<table><tr><td>A</td><td>C</td></tr><tr><td>B</td><td></td></tr></table>");
+    }
+
     // 단일 셀 테스트
     #[test]
     fn test_single_cell() {
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::table::{Table, TableBuilder, single_line};
+
+// GitHub Flavored Markdown 파이프 테이블 파싱: 처음 나오는 헤더 행 + 구분 행(| --- | :-: |)과 이어지는 행들을 읽는다
+// 구분 행의 칸 수는 헤더 행과 같아야 한다. 헤더 행은 헤더 셀(th)로 취급하고, 헤더보다 많은 셀은 버린다. 정렬 지정은 무시한다
+pub(crate) fn parse(text: &str, max_cells: Option<usize>) -> Result<Table, String> {
+    let lines: Vec<&str> = text.lines().map(str::trim).collect();
+    let start = (1..lines.len())
+        .find(|&i| {
+            lines[i - 1].contains('|')
+                && is_delimiter_row(lines[i])
+                && split_row(lines[i - 1]).len() == split_row(lines[i]).len()
+        })
+        .ok_or("No Markdown table found")?;
+
+    let header = split_row(lines[start - 1]);
+    let cols = header.len();
+    let body = lines[start + 1..]
+        .iter()
+        .take_while(|line| !line.is_empty() && line.contains('|'))
+        .map(|line| split_row(line));
+
+    let mut builder = TableBuilder::with_limit(max_cells);
+    builder.start_row();
+    for content in header {
+        builder.add_cell(content, 1, 1, true, HashMap::new())?;
+    }
+    for row in body {
+        builder.start_row();
+        for content in row.into_iter().take(cols) {
+            builder.add_cell(content, 1, 1, false, HashMap::new())?;
+        }
+    }
+    builder.finish(HashMap::new())
+}
+
+// Markdown 테이블 렌더링
+// Markdown 테이블은 헤더 행이 정확히 하나이고 병합을 지원하지 않으므로, 첫 행을 헤더로 쓰고
+// 병합 셀의 내용은 시작 위치에만 쓴다. 열은 표시 폭 기준으로 맞춘다
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    let rows = table.row_count();
+    let cols = table.col_count();
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    let mut texts = vec![vec![String::new(); cols]; rows];
+    for cell in table.cells() {
+        texts[cell.row][cell.col] = escape_markdown(&single_line(cell.content));
+    }
+    let widths: Vec<usize> = (0..cols)
+        .map(|c| texts.iter().map(|row| row[c].width()).max().unwrap_or(0).max(3))
+        .collect();
+
+    for (r, row) in texts.iter().enumerate() {
+        out.write_char('|')?;
+        for (c, text) in row.iter().enumerate() {
+            let padding = widths[c] - text.width();
+            write!(out, " {}{:padding$} |", text, "")?;
+        }
+        out.write_char('\n')?;
+        if r == 0 {
+            out.write_char('|')?;
+            for width in &widths {
+                write!(out, " {} |", "-".repeat(*width))?;
+            }
+            out.write_char('\n')?;
+        }
+    }
+    Ok(())
+}
+
+// | --- | :--: | ---: | 형태의 구분 행
+fn is_delimiter_row(line: &str) -> bool {
+    let cells = split_row(line);
+    line.contains('-')
+        && cells.iter().all(|cell| {
+            let dashes = cell.strip_prefix(':').unwrap_or(cell);
+            let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+            !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+        })
+}
+
+// 앞뒤의 |는 생략할 수 있고, \|는 셀 내용의 | 이다
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = if line.ends_with('|') && !line.ends_with("\\|") { &line[..line.len() - 1] } else { line };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                chars.next();
+                cell.push('|');
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            c => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+// 셀 구분자 | 는 \| 로 이스케이프한다
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 앞뒤 |를 생략한 행, 정렬 지정, 이스케이프, 모자라거나 넘치는 셀
+    #[test]
+    fn test_parse_markdown() {
+        let table = Table::from_markdown(r#"
+# 제목
+
+Name | Note
+:--- | ---:
+Kim  | a \| b
+| Lee |
+| Park | x | 버림 |
+
+다음 문단 | 아님
+"#).unwrap();
+        assert_eq!((table.row_count(), table.col_count()), (4, 2));
+        assert!(table.cell(0, 0).unwrap().is_header);
+        assert!(!table.cell(1, 0).unwrap().is_header);
+        assert_eq!(table.cell(1, 1).unwrap().content, "a | b");
+        assert!(table.cell(2, 1).is_none());
+        assert_eq!(table.cell(3, 1).unwrap().content, "x");
+
+        assert!(Table::from_markdown("| a | b |\n| c | d |").is_err());
+    }
+
+    // 첫 행이 헤더, 폭 정렬(한글 2칸), 병합 셀은 시작 위치에만
+    #[test]
+    fn test_render_markdown() {
+        let table = Table::from_html(r#"<table>
+            <tr><th>이름</th><th>Note</th></tr>
+            <tr><td rowspan="2">Kim</td><td>a|b</td></tr>
+            <tr><td>c</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_markdown(), concat!(
+            "| 이름 | Note |\n",
+            "| ---- | ---- |\n",
+            "| Kim  | a\\|b |\n",
+            "|      | c    |\n",
+        ));
+        assert_eq!(Table::from_markdown(&table.to_markdown()).unwrap().cell(1, 1).unwrap().content, "a|b");
+    }
+}
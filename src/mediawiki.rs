@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::html::{attr_entity, write_escaped};
+use crate::table::{Cell, MAX_COLSPAN, MAX_ROWSPAN, Table, TableBuilder, single_line, span_value};
+
+// MediaWiki 테이블 파싱: 첫 번째 `{| ... |}` 블록을 읽는다
+// 셀 안에 중첩된 테이블은 건너뛰고, 캡션(|+)과 행 속성(|-)은 무시한다
+pub(crate) fn parse(text: &str, max_cells: Option<usize>) -> Result<Table, String> {
+    let mut lines = text.lines().map(str::trim);
+
+    let table_attributes = loop {
+        match lines.next() {
+            Some(line) if line.starts_with("{|") => break parse_attributes(&line[2..]),
+            Some(_) => continue,
+            None => return Err("No MediaWiki table ({|) found".to_string()),
+        }
+    };
+
+    let mut builder = TableBuilder::with_limit(max_cells);
+    // 여러 줄에 걸칠 수 있으므로 마지막 셀은 다음 구분자가 나올 때 추가한다
+    let mut pending: Option<(String, bool)> = None;
+    let mut new_row = true;
+    let mut nested_depth = 0;
+    let mut closed = false;
+
+    for line in lines {
+        if nested_depth > 0 {
+            if line.starts_with("{|") {
+                nested_depth += 1;
+            } else if line.starts_with("|}") {
+                nested_depth -= 1;
+            }
+            continue;
+        }
+
+        if line.starts_with("{|") {
+            nested_depth = 1;
+        } else if line.starts_with("|}") {
+            closed = true;
+            break;
+        } else if line.starts_with("|-") || line.starts_with("|+") {
+            flush_cell(&mut builder, pending.take())?;
+            new_row |= line.starts_with("|-");
+        } else if let Some(marker) = line.chars().next().filter(|&c| c == '!' || c == '|') {
+            flush_cell(&mut builder, pending.take())?;
+            if new_row {
+                builder.start_row();
+                new_row = false;
+            }
+
+            let is_header = marker == '!';
+            let separators: &[&str] = if is_header { &["!!", "||"] } else { &["||"] };
+            let mut raw_cells = split_outside_links(&line[1..], separators);
+            let last = raw_cells.pop().unwrap_or_default();
+            for raw in raw_cells {
+                flush_cell(&mut builder, Some((raw, is_header)))?;
+            }
+            pending = Some((last, is_header));
+        } else if let Some((raw, _)) = pending.as_mut() {
+            raw.push('\n');
+            raw.push_str(line);
+        }
+    }
+
+    if !closed {
+        return Err("Unterminated MediaWiki table (missing |})".to_string());
+    }
+    flush_cell(&mut builder, pending)?;
+
+    builder.finish(table_attributes)
+}
+
+fn flush_cell(builder: &mut TableBuilder, cell: Option<(String, bool)>) -> Result<(), String> {
+    let Some((raw, is_header)) = cell else {
+        return Ok(());
+    };
+
+    // `속성 | 내용` 형태면 앞부분을 속성으로 해석
+    let mut parts = split_outside_links(&raw, &["|"]);
+    let (mut attributes, content) = if parts.len() > 1 {
+        let content = parts.split_off(1).join("|");
+        (parse_attributes(&parts[0]), content)
+    } else {
+        (HashMap::new(), raw)
+    };
+
+    let rowspan = span_value(attributes.remove("rowspan").as_deref(), MAX_ROWSPAN);
+    let colspan = span_value(attributes.remove("colspan").as_deref(), MAX_COLSPAN);
+
+    builder.add_cell(decode_entities(content.trim()), rowspan, colspan, is_header, attributes)
+}
+
+// 구분자로 나누되 [[링크|텍스트]], {{틀|인자}} 안의 구분자는 무시
+fn split_outside_links(text: &str, separators: &[&str]) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut rest = text;
+
+    while let Some(ch) = rest.chars().next() {
+        if rest.starts_with("[[") || rest.starts_with("{{") {
+            depth += 1;
+            current.push_str(&rest[..2]);
+            rest = &rest[2..];
+            continue;
+        }
+        if depth > 0 && (rest.starts_with("]]") || rest.starts_with("}}")) {
+            depth -= 1;
+            current.push_str(&rest[..2]);
+            rest = &rest[2..];
+            continue;
+        }
+        if depth == 0
+            && let Some(separator) = separators.iter().find(|s| rest.starts_with(**s))
+        {
+            parts.push(std::mem::take(&mut current));
+            rest = &rest[separator.len()..];
+            continue;
+        }
+        current.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+    parts.push(current);
+    parts
+}
+
+// name="value", name='value', name=value, name 형태의 속성 목록 파싱
+fn parse_attributes(text: &str) -> HashMap<String, String> {
+    let mut attributes = HashMap::new();
+    let mut rest = text.trim_start();
+
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value = if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            match after_eq.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &after_eq[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    rest = inner.get(end + 1..).unwrap_or("");
+                    inner[..end].to_string()
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    rest = &after_eq[end..];
+                    after_eq[..end].to_string()
+                }
+            }
+        } else {
+            String::new()
+        };
+
+        if !name.is_empty() {
+            attributes.insert(name, decode_entities(&value));
+        }
+        rest = rest.trim_start();
+    }
+
+    attributes
+}
+
+// 렌더러가 쓰는 문자 참조와 기본 HTML 엔티티 디코딩
+fn decode_entities(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        output.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').and_then(|semi| {
+            let entity = &rest[1..semi];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => entity.strip_prefix('#').and_then(|num| {
+                    let code = match num.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => num.parse::<u32>().ok(),
+                    };
+                    code.and_then(char::from_u32)
+                }),
+            };
+            ch.map(|ch| (ch, semi))
+        });
+        match decoded {
+            Some((ch, semi)) => {
+                output.push(ch);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+// MediaWiki 테이블 렌더링: `{| |- | ! |}` 문법, 셀은 한 줄에 하나씩 출력
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    out.write_str("{|")?;
+    for (attr_name, attr_value) in table.table_attributes() {
+        write_attribute(attr_name, attr_value, out)?;
+    }
+    out.write_char('\n')?;
+
+    for r in 0..table.row_count() {
+        out.write_str("|-\n")?;
+        for c in 0..table.col_count() {
+            if let Some(cell) = table.cell(r, c) {
+                write_cell(&cell, out)?;
+            } else if !table.is_covered(r, c) {
+                out.write_str("|\n")?;
+            }
+        }
+    }
+
+    out.write_str("|}\n")
+}
+
+fn write_cell(cell: &Cell<'_>, out: &mut impl fmt::Write) -> fmt::Result {
+    out.write_char(if cell.is_header { '!' } else { '|' })?;
+    let has_attributes = cell.rowspan > 1 || cell.colspan > 1 || cell.attributes().next().is_some();
+    if cell.rowspan > 1 {
+        write!(out, " rowspan=\"{}\"", cell.rowspan)?;
+    }
+    if cell.colspan > 1 {
+        write!(out, " colspan=\"{}\"", cell.colspan)?;
+    }
+    for (attr_name, attr_value) in cell.attributes() {
+        write_attribute(attr_name, attr_value, out)?;
+    }
+    if has_attributes {
+        out.write_str(" |")?;
+    }
+    writeln!(out, " {}", escape_wiki(&single_line(cell.content), cell.is_header))
+}
+
+// 속성 값은 HTML처럼 이스케이프하고, 셀 구분자로 읽히는 |와 !는 문자 참조로 쓴다 (파싱할 때 다시 디코딩된다)
+fn write_attribute(name: &str, value: &str, out: &mut impl fmt::Write) -> fmt::Result {
+    write!(out, " {}=\"", name)?;
+    write_escaped(value, wiki_attr_entity, out)?;
+    out.write_char('"')
+}
+
+fn wiki_attr_entity(c: char) -> Option<&'static str> {
+    match c {
+        '|' => Some("&#124;"),
+        '!' => Some("&#33;"),
+        c => attr_entity(c),
+    }
+}
+
+// 셀 구분자(|, 헤더 셀의 !)와 태그로 해석될 수 있는 문자 이스케이프
+fn escape_wiki(text: &str, is_header: bool) -> String {
+    let escaped = text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('|', "&#124;");
+    if is_header {
+        escaped.replace('!', "&#33;")
+    } else {
+        escaped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 한 줄/여러 줄 셀, 헤더 셀, 속성과 span 파싱 테스트
+    #[test]
+    fn test_parse_table() {
+        let table = Table::from_mediawiki(r#"
+{| class="wikitable"
+|+ Caption
+! Name !! scope="col" | Age
+|-
+| rowspan=2 style="color: red" | Kim || 30
+|-
+| 31
+|}
+"#).unwrap();
+        assert_eq!(table.row_count(), 3);
+        assert_eq!(table.col_count(), 2);
+        assert_eq!(table.table_attributes().collect::<Vec<_>>(), vec![("class", "wikitable")]);
+
+        let age = table.cell(0, 1).unwrap();
+        assert!(age.is_header);
+        assert_eq!((age.content, age.attr("scope")), ("Age", Some("col")));
+
+        let kim = table.cell(1, 0).unwrap();
+        assert!(!kim.is_header);
+        assert_eq!((kim.content, kim.rowspan), ("Kim", 2));
+        assert_eq!(kim.attr("style"), Some("color: red"));
+        assert_eq!(table.cell(2, 1).unwrap().content, "31");
+    }
+
+    // 링크 안의 |, 여러 줄 내용, 중첩 테이블 처리 테스트
+    #[test]
+    fn test_parse_links_multiline_and_nested() {
+        let table = Table::from_mediawiki(r#"{|
+| [[Page|label]] || first
+second
+|
+{|
+| nested
+|}
+|}"#).unwrap();
+        assert_eq!(table.cells().iter().map(|c| c.content).collect::<Vec<_>>(),
+            vec!["[[Page|label]]", "first\nsecond", ""]);
+    }
+
+    // 렌더링 결과를 다시 파싱하면 같은 테이블이어야 함
+    #[test]
+    fn test_roundtrip() {
+        let html = r#"<table><tr><th colspan="2">A|B &amp; C</th></tr><tr><td rowspan="2">D</td><td>E</td></tr><tr><td>F</td></tr></table>"#;
+        let table = Table::from_html(html).unwrap();
+        let reparsed = Table::from_mediawiki(&table.to_mediawiki()).unwrap();
+        assert_eq!(reparsed.to_html(), table.to_html());
+    }
+
+    // 속성 값의 |와 !는 셀 구분자로 읽히지 않도록 문자 참조로 쓴다
+    #[test]
+    fn test_roundtrip_attribute_separators() {
+        let html = r#"<table class="t|1"><tr><th class="a!!b">H</th><td class="a|b" title="x||y">D</td></tr></table>"#;
+        let table = Table::from_html(html).unwrap();
+        let wiki = table.to_mediawiki();
+        assert!(wiki.contains(r#"class="a&#124;b""#), "{}", wiki);
+        let reparsed = Table::from_mediawiki(&wiki).unwrap();
+        assert_eq!(reparsed.cells().len(), 2);
+        assert_eq!(reparsed.cell(0, 0).unwrap().attr("class"), Some("a!!b"));
+        assert_eq!(reparsed.cell(0, 1).unwrap().attr("class"), Some("a|b"));
+        assert_eq!(reparsed.cell(0, 1).unwrap().attr("title"), Some("x||y"));
+        assert_eq!(reparsed.table_attributes().collect::<Vec<_>>(), vec![("class", "t|1")]);
+    }
+
+    // 테이블이 없거나 닫히지 않은 경우 에러
+    #[test]
+    fn test_parse_errors() {
+        assert!(Table::from_mediawiki("no table").unwrap_err().contains("No MediaWiki table"));
+        assert!(Table::from_mediawiki("{|\n| A").unwrap_err().contains("Unterminated"));
+    }
+
+    // 헤더/데이터 셀과 테이블 속성 출력 테스트
+    #[test]
+    fn test_simple_table() {
+        let table = Table::from_html(r#"<table class="wikitable">
+            <tr><th>Name</th><th>Age</th></tr>
+            <tr><td>Kim</td><td>30</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_mediawiki(), concat!(
+            "{| class=\"wikitable\"\n",
+            "|-\n",
+            "! Name\n",
+            "! Age\n",
+            "|-\n",
+            "| Kim\n",
+            "| 30\n",
+            "|}\n",
+        ));
+    }
+
+    // 병합 셀과 셀 속성은 속성 구분자 | 뒤에 내용이 와야 함
+    #[test]
+    fn test_merged_cells_with_attributes() {
+        let table = Table::from_html(r#"<table>
+            <tr><td rowspan="2" class="m">A</td><td>B</td></tr>
+            <tr><td>C|D</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.transpose().to_mediawiki(), concat!(
+            "{|\n",
+            "|-\n",
+            "| colspan=\"2\" class=\"m\" | A\n",
+            "|-\n",
+            "| B\n",
+            "| C&#124;D\n",
+            "|}\n",
+        ));
+    }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::table::{Cell, CellData, EMPTY, Table};
+
+// 병합 해제 시 가려져 있던 위치를 채우는 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnmergeFill {
+    // 병합 셀의 내용을 모든 위치에 복사
+    Duplicate,
+    // 시작 위치에만 내용을 두고 나머지는 빈 셀
+    Empty,
+}
+
+impl FromStr for UnmergeFill {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<UnmergeFill, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "duplicate" => Ok(UnmergeFill::Duplicate),
+            "empty" => Ok(UnmergeFill::Empty),
+            _ => Err(format!("Unknown fill \"{}\" (expected one of: duplicate, empty)", s)),
+        }
+    }
+}
+
+// 인접한 같은 내용의 셀을 합치는 조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoMergeOptions {
+    // 세로로 인접한 셀을 rowspan으로 합칠지
+    pub rows: bool,
+    // 가로로 인접한 셀을 colspan으로 합칠지
+    pub columns: bool,
+    // 속성까지 같아야 합칠지
+    pub match_attributes: bool,
+    // 빈 셀끼리도 합칠지
+    pub merge_empty: bool,
+}
+
+impl Default for AutoMergeOptions {
+    fn default() -> Self {
+        AutoMergeOptions {
+            rows: true,
+            columns: true,
+            match_attributes: false,
+            merge_empty: false,
+        }
+    }
+}
+
+impl Table {
+    // 모든 rowspan/colspan 셀을 1x1 셀로 나눈다
+    // 속성은 시작 위치의 셀에만 남기고, 헤더 셀(th)을 나눈 셀은 모두 헤더 셀이 된다
+    pub fn unmerge(&self, fill: UnmergeFill) -> Table {
+        let mut table = self.clone();
+        let empty: Arc<str> = Arc::from("");
+
+        for (index, data) in self.cells.iter().enumerate() {
+            let (row, col) = data.origin();
+            let (rowspan, colspan) = data.spans();
+            if rowspan == 1 && colspan == 1 {
+                continue;
+            }
+            table.cells[index].rowspan = 1;
+            table.cells[index].colspan = 1;
+
+            for r in row..row + rowspan {
+                for c in col..col + colspan {
+                    let slot = r * table.cols + c;
+                    // 다른 셀이 덮어쓴 위치는 그대로 둔다
+                    if (r, c) == (row, col) || table.slots[slot] as usize != index {
+                        continue;
+                    }
+                    table.slots[slot] = table.cells.len() as u32;
+                    table.cells.push(CellData {
+                        row: r as u32,
+                        col: c as u32,
+                        rowspan: 1,
+                        colspan: 1,
+                        content: match fill {
+                            UnmergeFill::Duplicate => data.content.clone(),
+                            UnmergeFill::Empty => empty.clone(),
+                        },
+                        attributes: None,
+                        is_header: data.is_header,
+                    });
+                }
+            }
+        }
+
+        table
+    }
+
+    // 내용이 같은(옵션에 따라 속성도 같은) 인접 셀을 하나의 병합 셀로 합친다
+    // 행 우선으로 훑으며 가로로 먼저 넓히고, 그 폭 전체가 같은 행만큼 아래로 늘리므로 항상 직사각형 병합이 된다
+    // 이미 병합된 셀과 헤더/데이터 종류가 다른 셀은 합치지 않는다
+    pub fn auto_merge(&self, options: &AutoMergeOptions) -> Table {
+        let rows = self.row_count();
+        let cols = self.col_count();
+        let mut consumed = vec![vec![false; cols]; rows];
+        // 원래 테이블과 같은 크기의 그리드에 합친 셀을 직접 배치한다. 셀 수는 원래보다 늘지 않으므로 번호는 u32에 들어간다
+        let mut slots = vec![EMPTY; rows * cols];
+        let mut cells: Vec<CellData> = Vec::new();
+
+        for r in 0..rows {
+            for c in 0..cols {
+                if consumed[r][c] {
+                    continue;
+                }
+                let Some(cell) = self.cell(r, c) else {
+                    continue;
+                };
+
+                let (mut height, mut width) = (cell.rowspan, cell.colspan);
+                let single = height == 1 && width == 1;
+                if single && (options.merge_empty || !cell.content.is_empty()) {
+                    let matches = |row: usize, col: usize| {
+                        !consumed[row][col]
+                            && self.cell(row, col).is_some_and(|other| same_cell(&cell, &other, options))
+                    };
+                    if options.columns {
+                        while c + width < cols && matches(r, c + width) {
+                            width += 1;
+                        }
+                    }
+                    if options.rows {
+                        while r + height < rows && (c..c + width).all(|col| matches(r + height, col)) {
+                            height += 1;
+                        }
+                    }
+                }
+
+                let index = cells.len() as u32;
+                for row in r..r + height {
+                    for col in c..c + width {
+                        consumed[row][col] = true;
+                        slots[row * cols + col] = index;
+                    }
+                }
+
+                // 내용과 속성은 원래 셀의 것을 공유한다
+                let data = &self.cells[self.slots[r * cols + c] as usize];
+                cells.push(CellData {
+                    rowspan: height as u32,
+                    colspan: width as u32,
+                    ..data.clone()
+                });
+            }
+        }
+
+        Table {
+            attributes: self.attributes.clone(),
+            rows,
+            cols,
+            slots,
+            cells,
+        }
+    }
+}
+
+fn same_cell(cell: &Cell<'_>, other: &Cell<'_>, options: &AutoMergeOptions) -> bool {
+    other.rowspan == 1
+        && other.colspan == 1
+        && other.content == cell.content
+        && other.is_header == cell.is_header
+        && (!options.match_attributes
+            || cell.attributes().collect::<HashMap<_, _>>() == other.attributes().collect::<HashMap<_, _>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AutoMergeOptions, Table, UnmergeFill};
+
+    const MERGED: &str = r#"<table>
+        <tr><th colspan="2" class="h">H</th></tr>
+        <tr><td rowspan="2">A</td><td>B</td></tr>
+        <tr><td>C</td></tr>
+    </table>"#;
+
+    // 내용 복사 방식: 모든 위치에 같은 내용, 속성은 시작 셀에만
+    #[test]
+    fn test_unmerge_duplicate() {
+        let table = Table::from_html(MERGED).unwrap().unmerge(UnmergeFill::Duplicate);
+        assert_eq!(table.to_html(), concat!(
+            r#"<table><tr><th class="h">H</th><th>H</th></tr>"#,
+            "<tr><td>A</td><td>B</td></tr>",
+            "<tr><td>A</td><td>C</td></tr></table>",
+        ));
+    }
+
+    // 빈 셀 방식
+    #[test]
+    fn test_unmerge_empty() {
+        let table = Table::from_html(MERGED).unwrap().unmerge(UnmergeFill::Empty);
+        assert_eq!(table.to_html(), concat!(
+            r#"<table><tr><th class="h">H</th><th></th></tr>"#,
+            "<tr><td>A</td><td>B</td></tr>",
+            "<tr><td></td><td>C</td></tr></table>",
+        ));
+    }
+
+    // 바인딩에서 쓰는 채우기 방식 이름
+    #[test]
+    fn test_parse_fill() {
+        assert_eq!("empty".parse::<UnmergeFill>().unwrap(), UnmergeFill::Empty);
+        assert!("zero".parse::<UnmergeFill>().is_err());
+    }
+
+    // 전치된 테이블도 병합 셀 속성을 잃지 않아야 함
+    #[test]
+    fn test_unmerge_transposed() {
+        let table = Table::from_html(MERGED).unwrap().transpose().unmerge(UnmergeFill::Duplicate);
+        assert_eq!(table.cells().len(), 6);
+        assert_eq!(table.cell(0, 0).unwrap().attr("class"), Some("h"));
+        assert_eq!(table.cell(1, 0).unwrap().content, "H");
+        assert!(table.cell(1, 0).unwrap().is_header);
+        assert_eq!(table.cell(0, 2).unwrap().content, "A");
+    }
+
+    // 세로/가로로 같은 내용이 반복되면 직사각형으로 합쳐야 함
+    #[test]
+    fn test_auto_merge() {
+        let table = Table::from_html(r#"<table>
+            <tr><td>Dev</td><td>Q1</td><td>Q1</td></tr>
+            <tr><td>Dev</td><td>Q1</td><td>Q1</td></tr>
+            <tr><td>QA</td><td></td><td></td></tr>
+        </table>"#).unwrap();
+        let merged = table.auto_merge(&AutoMergeOptions::default());
+        assert_eq!(merged.to_html(), concat!(
+            r#"<table><tr><td rowspan="2">Dev</td><td rowspan="2" colspan="2">Q1</td></tr>"#,
+            "<tr></tr>",
+            "<tr><td>QA</td><td></td><td></td></tr></table>",
+        ));
+    }
+
+    // 방향 제한, 속성 비교, 헤더/데이터 구분
+    #[test]
+    fn test_auto_merge_options() {
+        let table = Table::from_html(r#"<table>
+            <tr><th>A</th><td>A</td><td class="x">B</td></tr>
+            <tr><th>A</th><td>B</td><td class="y">B</td></tr>
+        </table>"#).unwrap();
+
+        let rows_only = AutoMergeOptions { columns: false, ..AutoMergeOptions::default() };
+        let merged = table.auto_merge(&rows_only);
+        assert_eq!(merged.cell(0, 0).unwrap().rowspan, 2);
+        assert_eq!(merged.cell(0, 2).unwrap().rowspan, 2);
+        assert_eq!(merged.cell(0, 1).unwrap().colspan, 1);
+
+        let strict = AutoMergeOptions { match_attributes: true, ..AutoMergeOptions::default() };
+        let merged = table.auto_merge(&strict);
+        assert_eq!(merged.cell(0, 2).unwrap().rowspan, 1);
+        assert_eq!(merged.cell(1, 1).unwrap().colspan, 1);
+
+        let empty = AutoMergeOptions { merge_empty: true, ..AutoMergeOptions::default() };
+        let merged = Table::from_html("<table><tr><td></td><td></td></tr></table>").unwrap().auto_merge(&empty);
+        assert_eq!(merged.cell(0, 0).unwrap().colspan, 2);
+    }
+
+    // 병합 해제 후 다시 합치면 원래 테이블로 돌아와야 함
+    #[test]
+    fn test_auto_merge_inverts_unmerge() {
+        let table = Table::from_html(MERGED).unwrap();
+        let roundtrip = table.unmerge(UnmergeFill::Duplicate).auto_merge(&AutoMergeOptions::default());
+        assert_eq!(roundtrip.to_html(), table.to_html());
+    }
+
+    // 빈 위치가 있는 들쭉날쭉한 테이블도 합치고, 셀 내용은 복사하지 않고 공유한다
+    #[test]
+    fn test_auto_merge_ragged_shares_cells() {
+        let table = Table::from_html(r#"<table>
+            <tr><td>A</td><td>A</td><td rowspan="2">B</td></tr>
+            <tr><td>C</td></tr>
+        </table>"#).unwrap();
+        let merged = table.auto_merge(&AutoMergeOptions::default());
+        assert_eq!(merged.cell(0, 0).unwrap().colspan, 2);
+        assert_eq!(merged.cell(0, 2).unwrap().rowspan, 2);
+        assert!(merged.cell(1, 1).is_none() && !merged.is_covered(1, 1));
+        assert!(std::sync::Arc::ptr_eq(&table.cells[0].content, &merged.cells[0].content));
+    }
+}
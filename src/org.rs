@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::table::{Table, TableBuilder, header_row_count, single_line};
+
+// Org-mode 테이블 파싱: 처음 나오는 `|`로 시작하는 연속된 줄들을 읽는다
+// 첫 번째 구분선(|-) 위의 행은 헤더 셀(th)로 취급하고, 정렬 지정 행(<l>, <r>, <10> 등)은 무시한다
+pub(crate) fn parse(text: &str, max_cells: Option<usize>) -> Result<Table, String> {
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| !line.starts_with('|'))
+        .take_while(|line| line.starts_with('|'))
+        .collect();
+    if lines.is_empty() {
+        return Err("No Org table found".to_string());
+    }
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut header_rows = 0;
+    for line in lines {
+        if line.starts_with("|-") {
+            if header_rows == 0 {
+                header_rows = rows.len();
+            }
+            continue;
+        }
+
+        let inner = line[1..].strip_suffix('|').unwrap_or(&line[1..]);
+        let cells: Vec<String> = inner
+            .split('|')
+            .map(|cell| cell.trim().replace("\\vert{}", "|"))
+            .collect();
+        let is_cookie_row = cells.iter().any(|cell| !cell.is_empty())
+            && cells.iter().all(|cell| cell.is_empty() || is_alignment_cookie(cell));
+        if is_cookie_row {
+            continue;
+        }
+        rows.push(cells);
+    }
+    // 구분선 아래에 데이터 행이 없으면 헤더가 아님
+    if header_rows == rows.len() {
+        header_rows = 0;
+    }
+
+    let mut builder = TableBuilder::with_limit(max_cells);
+    for (row_idx, row) in rows.into_iter().enumerate() {
+        builder.start_row();
+        for content in row {
+            builder.add_cell(content, 1, 1, row_idx < header_rows, HashMap::new())?;
+        }
+    }
+
+    builder.finish(HashMap::new())
+}
+
+// Org-mode 테이블 렌더링
+// Org 테이블은 병합을 지원하지 않으므로 병합 셀의 내용은 시작 위치에만 쓰고 나머지는 비워 둔다
+// 열은 표시 폭 기준으로 맞추고, 숫자가 대부분인 열은 오른쪽 정렬한다
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    let rows = table.row_count();
+    let cols = table.col_count();
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    let cells = table.cells();
+    let owners = table.owners(&cells);
+    let header_rows = header_row_count(&cells, &owners);
+
+    let mut texts = vec![vec![String::new(); cols]; rows];
+    for cell in &cells {
+        texts[cell.row][cell.col] = escape_org(&single_line(cell.content));
+    }
+
+    let widths: Vec<usize> = (0..cols)
+        .map(|c| texts.iter().map(|row| row[c].width()).max().unwrap_or(0).max(1))
+        .collect();
+    let right_align: Vec<bool> = (0..cols)
+        .map(|c| is_numeric_column(texts.iter().skip(header_rows).map(|row| row[c].as_str())))
+        .collect();
+
+    let hline = format!(
+        "|{}|\n",
+        widths.iter().map(|w| "-".repeat(w + 2)).collect::<Vec<_>>().join("+")
+    );
+
+    for (r, row) in texts.iter().enumerate() {
+        if header_rows > 0 && r == header_rows {
+            out.write_str(&hline)?;
+        }
+        out.write_char('|')?;
+        for (c, text) in row.iter().enumerate() {
+            let padding = widths[c] - text.width();
+            if right_align[c] {
+                write!(out, " {:padding$}{} |", "", text)?;
+            } else {
+                write!(out, " {}{:padding$} |", text, "")?;
+            }
+        }
+        out.write_char('\n')?;
+    }
+    Ok(())
+}
+
+// <l>, <c>, <r>, <10>, <r10> 같은 열 정렬/폭 지정
+fn is_alignment_cookie(cell: &str) -> bool {
+    cell.strip_prefix('<')
+        .and_then(|s| s.strip_suffix('>'))
+        .map(|s| s.strip_prefix(['l', 'c', 'r']).unwrap_or(s))
+        .is_some_and(|s| s.chars().all(|c| c.is_ascii_digit()))
+}
+
+// 비어 있지 않은 셀의 절반 이상이 숫자인 열 (Org의 기본 정렬 규칙)
+fn is_numeric_column<'a>(texts: impl Iterator<Item = &'a str>) -> bool {
+    let (mut filled, mut numeric) = (0, 0);
+    for text in texts.filter(|text| !text.is_empty()) {
+        filled += 1;
+        let number = text.trim_end_matches('%').replace(',', "");
+        if number.parse::<f64>().is_ok() {
+            numeric += 1;
+        }
+    }
+    filled > 0 && numeric * 2 >= filled
+}
+
+// 셀 구분자 | 는 Org 엔티티로 바꾼다
+fn escape_org(text: &str) -> String {
+    text.replace('|', "\\vert{}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 구분선 위 행은 헤더, 정렬 지정 행은 무시
+    #[test]
+    fn test_parse_table() {
+        let table = Table::from_org(r#"
+#+NAME: people
+| Name | Age |
+| <l>  | <r> |
+|------+-----|
+| Kim  |  30 |
+| a\vert{}b |
+#+TBLFM: $2=$1
+"#).unwrap();
+        assert_eq!(table.row_count(), 3);
+        assert_eq!(table.col_count(), 2);
+        assert!(table.cell(0, 0).unwrap().is_header);
+        assert!(!table.cell(1, 0).unwrap().is_header);
+        assert_eq!(table.cell(1, 1).unwrap().content, "30");
+        assert_eq!(table.cell(2, 0).unwrap().content, "a|b");
+        assert!(table.cell(2, 1).is_none());
+    }
+
+    // 맨 위 테두리 구분선만 있으면 헤더 없음
+    #[test]
+    fn test_parse_without_header() {
+        let table = Table::from_org("|---|\n| a |\n| b |\n|---|\n").unwrap();
+        assert_eq!(table.row_count(), 2);
+        assert!(!table.cell(0, 0).unwrap().is_header);
+        assert!(Table::from_org("no table here").is_err());
+    }
+
+    // 전치 후 출력: 폭 정렬(한글 2칸), 숫자 열 오른쪽 정렬. 헤더가 첫 열로 옮겨가면 구분선 없음
+    #[test]
+    fn test_render_transposed() {
+        let table = Table::from_org(r#"
+| 이름 | Kim | Lee |
+|------+-----+-----|
+| Age  | 30  | 7   |
+"#).unwrap();
+        assert_eq!(table.transpose().to_org(), concat!(
+            "| 이름 | Age |\n",
+            "| Kim  |  30 |\n",
+            "| Lee  |   7 |\n",
+        ));
+    }
+
+    // 헤더 행과 병합 셀 출력
+    #[test]
+    fn test_render_header_and_merged_cells() {
+        let table = Table::from_html(r#"<table>
+            <tr><th>Team</th><th>Name</th></tr>
+            <tr><td rowspan="2">Dev</td><td>Kim|Lee</td></tr>
+            <tr><td>Park</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_org(), concat!(
+            "| Team | Name          |\n",
+            "|------+---------------|\n",
+            "| Dev  | Kim\\vert{}Lee |\n",
+            "|      | Park          |\n",
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+use rayon::prelude::*;
+use scraper::{Html, Selector};
+
+use crate::html;
+use crate::table::{Table, TransposeOptions};
+
+// 여러 HTML 입력을 병렬로 전치한다. 결과는 입력 순서대로이고, 입력마다 따로 성공/실패를 돌려준다
+pub fn transpose_many<S: AsRef<str> + Sync>(inputs: &[S], options: &TransposeOptions) -> Vec<Result<String, String>> {
+    inputs
+        .par_iter()
+        .map(|input| crate::transpose_with_options(input.as_ref(), options))
+        .collect()
+}
+
+// 문서 안의 모든 <table>을 (중첩된 테이블도 따로) 문서 순서대로 전치한다
+// DOM은 스레드 간에 공유할 수 없으므로 테이블 모델은 차례로 읽고, 전치와 출력만 병렬로 처리한다
+pub fn transpose_tables(html: &str, options: &TransposeOptions) -> Vec<Result<String, String>> {
+    let document = Html::parse_document(html);
+    let Ok(table_selector) = Selector::parse("table") else {
+        return Vec::new();
+    };
+    let tables: Vec<Result<Table, String>> = document.select(&table_selector).map(|table| html::read_table(table, None)).collect();
+
+    tables
+        .into_par_iter()
+        .map(|table| table.map(|table| table.transpose_with(options).to_html()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 결과는 입력 순서를 지키고, 실패한 입력만 오류여야 함
+    #[test]
+    fn test_transpose_many() {
+        let inputs: Vec<String> = (0..50)
+            .map(|i| match i % 10 {
+                7 => "<p>테이블 없음</p>".to_string(),
+                _ => format!("<table><tr><td>{}</td><td>x</td></tr></table>", i),
+            })
+            .collect();
+        let results = transpose_many(&inputs, &TransposeOptions::default());
+        assert_eq!(results.len(), 50);
+        for (i, result) in results.iter().enumerate() {
+            if i % 10 == 7 {
+                assert!(result.is_err());
+            } else {
+                assert_eq!(result.as_deref().unwrap(), format!("<table><tr><td>{}</td></tr><tr><td>x</td></tr></table>", i));
+            }
+        }
+    }
+
+    // 문서 안의 테이블을 문서 순서대로 전치
+    #[test]
+    fn test_transpose_tables() {
+        let html = "<table><tr><td>1</td><td>2</td></tr></table><div><table id=\"b\"><tr><td>3</td></tr><tr><td>4</td></tr></table></div>";
+        let results = transpose_tables(html, &TransposeOptions::default());
+        assert_eq!(results, vec![
+            Ok("<table><tr><td>1</td></tr><tr><td>2</td></tr></table>".to_string()),
+            Ok("<table id=\"b\"><tr><td>3</td><td>4</td></tr></table>".to_string()),
+        ]);
+        assert!(transpose_tables("<p>x</p>", &TransposeOptions::default()).is_empty());
+    }
+}
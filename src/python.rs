@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{AutoMergeOptions, Format, Table, TransposeOptions, UnmergeFill};
+
+// Python 모듈. 오류는 메시지를 담은 ValueError로 던진다
+// 내부 처리는 String 오류를 돌려주는 함수로 나눠 두어 Python 객체 없이도 테스트할 수 있게 한다
+#[pymodule]
+fn html_transpose(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(transpose, module)?)?;
+    module.add_function(wrap_pyfunction!(convert, module)?)?;
+    module.add_class::<PyTable>()?;
+    module.add_class::<PyCell>()?;
+    Ok(())
+}
+
+fn value_error(message: String) -> PyErr {
+    PyValueError::new_err(message)
+}
+
+#[pyfunction]
+#[pyo3(signature = (html, *, swap_scope = true, infer_scope = false, id_prefix = None, generate_headers = false, swap_axis_styles = false))]
+fn transpose(
+    html: &str,
+    swap_scope: bool,
+    infer_scope: bool,
+    id_prefix: Option<String>,
+    generate_headers: bool,
+    swap_axis_styles: bool,
+) -> PyResult<String> {
+    let options = TransposeOptions { swap_scope, infer_scope, id_prefix, generate_headers, swap_axis_styles };
+    crate::transpose_with_options(html, &options).map_err(value_error)
+}
+
+// 포맷 이름(html, markdown, csv 등 명령줄의 --from/--to 값)으로 테이블을 다른 포맷으로 바꾼다
+#[pyfunction]
+fn convert(text: &str, from_format: &str, to_format: &str) -> PyResult<String> {
+    convert_text(text, from_format, to_format).map_err(value_error)
+}
+
+fn convert_text(text: &str, from: &str, to: &str) -> Result<String, String> {
+    let to: Format = to.parse()?;
+    Ok(Table::parse(text, from.parse()?)?.render(to))
+}
+
+// 테이블 모델 (값을 바꾸는 메서드는 새 Table을 돌려준다)
+#[pyclass(name = "Table", module = "html_transpose", frozen)]
+struct PyTable {
+    table: Table,
+}
+
+#[pymethods]
+impl PyTable {
+    #[staticmethod]
+    #[pyo3(signature = (text, format = "html"))]
+    fn parse(text: &str, format: &str) -> PyResult<PyTable> {
+        parse_table(text, format).map_err(value_error)
+    }
+
+    #[getter]
+    fn row_count(&self) -> usize {
+        self.table.row_count()
+    }
+
+    #[getter]
+    fn col_count(&self) -> usize {
+        self.table.col_count()
+    }
+
+    // (row, col)에서 시작하는 셀. 병합으로 가려진 위치나 빈 위치는 None
+    fn cell(&self, row: usize, col: usize) -> Option<PyCell> {
+        self.table.cell(row, col).map(PyCell::from)
+    }
+
+    fn cells(&self) -> Vec<PyCell> {
+        self.table.cells().into_iter().map(PyCell::from).collect()
+    }
+
+    #[pyo3(signature = (*, swap_scope = true, infer_scope = false, id_prefix = None, generate_headers = false, swap_axis_styles = false))]
+    fn transpose(
+        &self,
+        swap_scope: bool,
+        infer_scope: bool,
+        id_prefix: Option<String>,
+        generate_headers: bool,
+        swap_axis_styles: bool,
+    ) -> PyTable {
+        let options = TransposeOptions { swap_scope, infer_scope, id_prefix, generate_headers, swap_axis_styles };
+        PyTable { table: self.table.transpose_with(&options) }
+    }
+
+    // cw, ccw, 180
+    fn rotate(&self, direction: &str) -> PyResult<PyTable> {
+        rotate_table(&self.table, direction).map_err(value_error)
+    }
+
+    #[pyo3(signature = (fill = "duplicate"))]
+    fn unmerge(&self, fill: &str) -> PyResult<PyTable> {
+        unmerge_table(&self.table, fill).map_err(value_error)
+    }
+
+    fn auto_merge(&self) -> PyTable {
+        PyTable { table: self.table.auto_merge(&AutoMergeOptions::default()) }
+    }
+
+    fn render(&self, format: &str) -> PyResult<String> {
+        render_table(&self.table, format).map_err(value_error)
+    }
+
+    fn to_html(&self) -> String {
+        self.table.to_html()
+    }
+
+    // 병합 셀을 펼친 리스트의 리스트
+    #[pyo3(signature = (fill = "duplicate"))]
+    fn to_list(&self, fill: &str) -> PyResult<Vec<Vec<String>>> {
+        table_rows(&self.table, fill).map_err(value_error)
+    }
+
+    // 헤더 행을 키로 쓰는 dict 목록: pandas.DataFrame(table.to_records())
+    fn to_records<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let records = self.table.to_records();
+        records
+            .rows
+            .iter()
+            .map(|row| {
+                let record = PyDict::new(py);
+                for (column, value) in records.columns.iter().zip(row) {
+                    record.set_item(column, value)?;
+                }
+                Ok(record)
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Table {}x{}>", self.table.row_count(), self.table.col_count())
+    }
+}
+
+fn parse_table(text: &str, format: &str) -> Result<PyTable, String> {
+    Ok(PyTable { table: Table::parse(text, format.parse()?)? })
+}
+
+fn rotate_table(table: &Table, direction: &str) -> Result<PyTable, String> {
+    Ok(PyTable { table: table.rotate(direction.parse()?) })
+}
+
+fn unmerge_table(table: &Table, fill: &str) -> Result<PyTable, String> {
+    Ok(PyTable { table: table.unmerge(fill.parse()?) })
+}
+
+fn render_table(table: &Table, format: &str) -> Result<String, String> {
+    Ok(table.render(format.parse()?))
+}
+
+fn table_rows(table: &Table, fill: &str) -> Result<Vec<Vec<String>>, String> {
+    Ok(table.to_rows(fill.parse::<UnmergeFill>()?))
+}
+
+// 셀 하나 (값 복사본)
+#[pyclass(name = "Cell", module = "html_transpose", frozen, get_all)]
+struct PyCell {
+    row: usize,
+    col: usize,
+    rowspan: usize,
+    colspan: usize,
+    content: String,
+    is_header: bool,
+    attributes: HashMap<String, String>,
+}
+
+#[pymethods]
+impl PyCell {
+    fn __repr__(&self) -> String {
+        format!("<Cell ({}, {}) {:?}>", self.row, self.col, self.content)
+    }
+}
+
+impl From<crate::Cell<'_>> for PyCell {
+    fn from(cell: crate::Cell<'_>) -> PyCell {
+        PyCell {
+            row: cell.row,
+            col: cell.col,
+            rowspan: cell.rowspan,
+            colspan: cell.colspan,
+            content: cell.content.to_string(),
+            is_header: cell.is_header,
+            attributes: cell.attributes().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"<table><tr><th colspan="2" class="h">H</th></tr><tr><td>A</td><td>B</td></tr></table>"#;
+
+    // 포맷, 방향, 채우기 이름과 테이블 메서드
+    #[test]
+    fn test_bindings() {
+        let table = parse_table(HTML, "html").unwrap();
+        assert_eq!((table.row_count(), table.col_count()), (2, 2));
+        let header = table.cell(0, 0).unwrap();
+        assert!(header.is_header && header.colspan == 2);
+        assert_eq!(header.attributes.get("class").map(String::as_str), Some("h"));
+        assert!(table.cell(0, 1).is_none());
+
+        assert_eq!(rotate_table(&table.table, "180").unwrap().cell(0, 0).unwrap().content, "B");
+        assert_eq!(unmerge_table(&table.table, "empty").unwrap().cells().len(), 4);
+        assert_eq!(table_rows(&table.table, "duplicate").unwrap(), vec![vec!["H", "H"], vec!["A", "B"]]);
+        assert_eq!(table_rows(&table.table, "empty").unwrap(), vec![vec!["H", ""], vec!["A", "B"]]);
+        assert_eq!(render_table(&table.table, "csv").unwrap(), "H,\r\nA,B\r\n");
+        assert_eq!(convert_text("| a | b |", "org", "html").unwrap(), "<table><tr><td>a</td><td>b</td></tr></table>");
+    }
+
+    // 키워드 인자 옵션이 TransposeOptions로 전달되는지
+    #[test]
+    fn test_transpose_options() {
+        let table = parse_table(HTML, "html").unwrap();
+        let transposed = table.transpose(true, true, Some("t-".to_string()), false, false);
+        assert_eq!(transposed.cell(0, 0).unwrap().rowspan, 2);
+        assert_eq!(transposed.cell(0, 0).unwrap().attributes.get("scope").map(String::as_str), Some("rowgroup"));
+        let plain = table.transpose(true, false, None, false, false);
+        assert!(!plain.cell(0, 0).unwrap().attributes.contains_key("scope"));
+    }
+
+    // 잘못된 인자는 메시지를 담은 오류로, Python에서는 ValueError로
+    #[test]
+    fn test_errors() {
+        assert!(convert_text("| a |", "org", "xlsx").unwrap_err().starts_with("Unknown format"));
+        assert!(convert_text("x", "rst", "html").is_err());
+        assert!(parse_table("<p>x</p>", "html").is_err());
+        let table = parse_table(HTML, "html").unwrap();
+        assert!(rotate_table(&table.table, "left").is_err());
+        assert!(unmerge_table(&table.table, "zero").is_err());
+        assert!(table_rows(&table.table, "zero").is_err());
+        assert!(render_table(&table.table, "xlsx").is_err());
+
+        Python::initialize();
+        Python::attach(|py| {
+            let error = convert("<p>x</p>", "html", "csv").unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            assert_eq!(error.value(py).to_string(), "No <table> element found");
+            assert!(PyTable::parse(HTML, "xlsx").is_err_and(|error| error.is_instance_of::<PyValueError>(py)));
+        });
+    }
+}
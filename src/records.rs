@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use crate::merge::UnmergeFill;
+use crate::table::{Table, header_row_count};
+
+// 헤더 행을 열 이름으로, 나머지 행을 데이터로 나눈 테이블 (DataFrame 같은 표 형식 데이터로 옮길 때)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Records {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    // 병합 셀을 펼친 행 목록. 가려진 위치는 fill에 따라 병합 셀의 내용 또는 빈 문자열
+    pub fn to_rows(&self, fill: UnmergeFill) -> Vec<Vec<String>> {
+        let unmerged = self.unmerge(fill);
+        (0..unmerged.row_count())
+            .map(|r| {
+                (0..unmerged.col_count())
+                    .map(|c| unmerged.cell(r, c).map_or_else(String::new, |cell| cell.content.to_string()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    // 맨 위의 헤더 행(모두 th인 행)을 열 이름으로 쓴다. 헤더 행이 없으면 첫 행을 쓴다
+    // 여러 헤더 행은 위에서부터 " / "로 잇고, 이름이 없는 열은 `Unnamed: {열}`, 같은 이름은 `.1`, `.2`를 붙인다
+    pub fn to_records(&self) -> Records {
+        let cells = self.cells();
+        let owners = self.owners(&cells);
+        let header_rows = header_row_count(&cells, &owners).max(1).min(self.row_count());
+
+        let mut rows = self.to_rows(UnmergeFill::Duplicate);
+        let data = rows.split_off(header_rows);
+
+        let mut used = HashSet::new();
+        let columns = (0..self.col_count())
+            .map(|c| {
+                let mut parts: Vec<&str> = Vec::new();
+                for row in &rows {
+                    let text = row[c].as_str();
+                    if !text.is_empty() && parts.last() != Some(&text) {
+                        parts.push(text);
+                    }
+                }
+                let name = if parts.is_empty() { format!("Unnamed: {}", c) } else { parts.join(" / ") };
+                let mut unique = name.clone();
+                let mut n = 0;
+                while !used.insert(unique.clone()) {
+                    n += 1;
+                    unique = format!("{}.{}", name, n);
+                }
+                unique
+            })
+            .collect();
+
+        Records { columns, rows: data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Table, UnmergeFill};
+
+    const TABLE: &str = r#"<table>
+        <tr><th rowspan="2">이름</th><th colspan="2">점수</th><th></th></tr>
+        <tr><th>국어</th><th>국어</th><th></th></tr>
+        <tr><td>A</td><td rowspan="2">90</td><td>80</td><td>x</td></tr>
+        <tr><td>B</td><td>70</td><td>y</td></tr>
+    </table>"#;
+
+    // 병합 셀을 펼친 행 목록
+    #[test]
+    fn test_to_rows() {
+        let table = Table::from_html(TABLE).unwrap();
+        assert_eq!(table.to_rows(UnmergeFill::Duplicate)[3], vec!["B", "90", "70", "y"]);
+        assert_eq!(table.to_rows(UnmergeFill::Empty)[3], vec!["B", "", "70", "y"]);
+    }
+
+    // 헤더 행으로 열 이름 만들기 (여러 줄, 빈 이름, 중복 이름)
+    #[test]
+    fn test_to_records() {
+        let records = Table::from_html(TABLE).unwrap().to_records();
+        assert_eq!(records.columns, vec!["이름", "점수 / 국어", "점수 / 국어.1", "Unnamed: 3"]);
+        assert_eq!(records.rows, vec![vec!["A", "90", "80", "x"], vec!["B", "90", "70", "y"]]);
+
+        // 헤더 셀이 없으면 첫 행이 열 이름
+        let records = Table::from_html("<table><tr><td>a</td></tr><tr><td>1</td></tr></table>").unwrap().to_records();
+        assert_eq!(records.columns, vec!["a"]);
+        assert_eq!(records.rows, vec![vec!["1"]]);
+    }
+}
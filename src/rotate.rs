@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use crate::table::Table;
+
+// 테이블 회전 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    // 시계 방향 90도: 첫 열이 첫 행이 되고, 마지막 행이 첫 열이 된다
+    Clockwise,
+    // 반시계 방향 90도: 마지막 열이 첫 행이 되고, 첫 행이 첫 열이 된다
+    CounterClockwise,
+    // 180도
+    HalfTurn,
+}
+
+impl FromStr for Rotation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Rotation, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "cw" => Ok(Rotation::Clockwise),
+            "ccw" => Ok(Rotation::CounterClockwise),
+            "180" => Ok(Rotation::HalfTurn),
+            _ => Err(format!("Unknown rotation \"{}\" (expected one of: cw, ccw, 180)", s)),
+        }
+    }
+}
+
+impl Table {
+    // 셀 배치를 회전한다. 전치와 달리 행 또는 열의 순서가 뒤집힌다
+    // 90도 회전에서는 전치처럼 rowspan/colspan과 scope를 서로 바꾼다
+    pub fn rotate(&self, rotation: Rotation) -> Table {
+        let rows = self.row_count();
+        let cols = self.col_count();
+        match rotation {
+            Rotation::Clockwise => {
+                let mut rotated = self.remap(cols, rows, true, |row, col| (col, rows - 1 - row));
+                rotated.swap_scope();
+                rotated
+            }
+            Rotation::CounterClockwise => {
+                let mut rotated = self.remap(cols, rows, true, |row, col| (cols - 1 - col, row));
+                rotated.swap_scope();
+                rotated
+            }
+            Rotation::HalfTurn => self.remap(rows, cols, false, |row, col| (rows - 1 - row, cols - 1 - col)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Rotation, Table};
+
+    const TABLE: &str = r#"<table>
+        <tr><th scope="col">A</th><th scope="col">B</th></tr>
+        <tr><td rowspan="2">1</td><td>2</td></tr>
+        <tr><td>3</td></tr>
+    </table>"#;
+
+    // 시계 방향: 마지막 행이 첫 열로
+    #[test]
+    fn test_rotate_clockwise() {
+        let table = Table::from_html(TABLE).unwrap().rotate(Rotation::Clockwise);
+        assert_eq!(table.to_html(), concat!(
+            r#"<table><tr><td colspan="2">1</td><th scope="row">A</th></tr>"#,
+            r#"<tr><td>3</td><td>2</td><th scope="row">B</th></tr></table>"#,
+        ));
+    }
+
+    // 반시계 방향과 180도
+    #[test]
+    fn test_rotate_counter_clockwise_and_half_turn() {
+        let table = Table::from_html(TABLE).unwrap();
+        let rotated = table.rotate(Rotation::CounterClockwise);
+        assert_eq!(rotated.to_html(), concat!(
+            r#"<table><tr><th scope="row">B</th><td>2</td><td>3</td></tr>"#,
+            r#"<tr><th scope="row">A</th><td colspan="2">1</td></tr></table>"#,
+        ));
+
+        let rotated = table.rotate(Rotation::HalfTurn);
+        assert_eq!(rotated.cell(0, 0).unwrap().content, "3");
+        assert_eq!(rotated.cell(0, 1).unwrap().rowspan, 2);
+        assert_eq!(rotated.cell(2, 0).unwrap().attr("scope"), Some("col"));
+
+        // 네 번 돌리면 원래 테이블
+        let full = rotated.rotate(Rotation::Clockwise).rotate(Rotation::Clockwise);
+        assert_eq!(full.to_html(), table.to_html());
+    }
+
+    // 바인딩에서 쓰는 방향 이름
+    #[test]
+    fn test_parse_rotation() {
+        assert_eq!("CW".parse::<Rotation>().unwrap(), Rotation::Clockwise);
+        assert_eq!("180".parse::<Rotation>().unwrap(), Rotation::HalfTurn);
+        assert!("left".parse::<Rotation>().is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::table::{Cell, Table, header_row_count, single_line};
+
+// reStructuredText grid table 렌더링
+// 병합 셀은 내부 경계선을 지워서 표현하고, 폭은 화면 표시 폭(CJK = 2칸) 기준으로 계산한다
+pub(crate) fn write(table: &Table, out: &mut impl fmt::Write) -> fmt::Result {
+    let rows = table.row_count();
+    let cols = table.col_count();
+    if rows == 0 || cols == 0 {
+        return Ok(());
+    }
+
+    let cells = table.cells();
+    let owners = table.owners(&cells);
+    let texts: Vec<String> = cells.iter().map(|cell| single_line(cell.content)).collect();
+
+    let widths = column_widths(cols, &cells, &texts);
+    let header_rows = header_row_count(&cells, &owners);
+
+    // 경계선 존재 여부: 인접한 두 위치의 소유 셀이 다르면 선을 긋는다
+    let horizontal = |i: usize, c: usize| i == 0 || i == rows || owners[i - 1][c] != owners[i][c];
+    let vertical = |r: usize, j: usize| j == 0 || j == cols || owners[r][j - 1] != owners[r][j];
+
+    // 한 줄씩 만들어서 쓴다
+    let mut line = String::new();
+    for i in 0..=rows {
+        let line_char = if header_rows > 0 && i == header_rows { '=' } else { '-' };
+
+        // 경계선 줄
+        for j in 0..=cols {
+            let up = i > 0 && vertical(i - 1, j);
+            let down = i < rows && vertical(i, j);
+            let left = j > 0 && horizontal(i, j - 1);
+            let right = j < cols && horizontal(i, j);
+            line.push(match (up || down, left || right) {
+                (true, true) => '+',
+                (false, true) => line_char,
+                (true, false) => '|',
+                (false, false) => ' ',
+            });
+            if let Some(&width) = widths.get(j) {
+                let fill = if horizontal(i, j) { line_char } else { ' ' };
+                line.extend(std::iter::repeat_n(fill, width));
+            }
+        }
+        line.push('\n');
+        out.write_str(&line)?;
+        line.clear();
+
+        let Some(row_owners) = owners.get(i) else {
+            break;
+        };
+
+        // 내용 줄: 셀의 첫 행에만 내용을 쓰고 병합으로 이어지는 행은 비워 둔다
+        line.push('|');
+        let mut c = 0;
+        while c < cols {
+            let owner = row_owners[c];
+            let mut end = c + 1;
+            while end < cols && !vertical(i, end) {
+                end += 1;
+            }
+            let width = widths[c..end].iter().sum::<usize>() + (end - c - 1);
+            let text = match cells.get(owner) {
+                Some(cell) if cell.row == i => texts[owner].as_str(),
+                _ => "",
+            };
+            line.push(' ');
+            line.push_str(text);
+            line.extend(std::iter::repeat_n(' ', width - 1 - text.width()));
+            line.push('|');
+            c = end;
+        }
+        line.push('\n');
+        out.write_str(&line)?;
+        line.clear();
+    }
+    Ok(())
+}
+
+// 열 폭 계산: 좌우 여백 1칸씩 포함. 병합 셀은 모자란 폭을 마지막 열에 더한다
+fn column_widths(cols: usize, cells: &[Cell<'_>], texts: &[String]) -> Vec<usize> {
+    let mut widths = vec![3; cols];
+
+    let mut order: Vec<usize> = (0..cells.len()).collect();
+    order.sort_by_key(|&idx| cells[idx].colspan);
+
+    for idx in order {
+        let cell = &cells[idx];
+        let needed = texts[idx].width() + 2;
+        let span = &mut widths[cell.col..cell.col + cell.colspan];
+        let available = span.iter().sum::<usize>() + (cell.colspan - 1);
+        if needed > available {
+            span[cell.colspan - 1] += needed - available;
+        }
+    }
+
+    widths
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 단순 테이블 + 헤더 구분선 테스트
+    #[test]
+    fn test_simple_grid_table() {
+        let table = Table::from_html(r#"<table>
+            <tr><th>Name</th><th>Age</th></tr>
+            <tr><td>Kim</td><td>30</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_rst(), concat!(
+            "+------+-----+\n",
+            "| Name | Age |\n",
+            "+======+=====+\n",
+            "| Kim  | 30  |\n",
+            "+------+-----+\n",
+        ));
+    }
+
+    // rowspan/colspan 셀은 내부 경계선 없이 출력되어야 함
+    #[test]
+    fn test_merged_cells() {
+        let table = Table::from_html(r#"<table>
+            <tr><td rowspan="2">A</td><td colspan="2">BC</td></tr>
+            <tr><td>D</td><td>E</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_rst(), concat!(
+            "+---+-------+\n",
+            "| A | BC    |\n",
+            "|   +---+---+\n",
+            "|   | D | E |\n",
+            "+---+---+---+\n",
+        ));
+    }
+
+    // 전치 후 병합 방향이 바뀌어 출력되는지 테스트
+    #[test]
+    fn test_transposed_merged_cells() {
+        let table = Table::from_html(r#"<table>
+            <tr><td colspan="2">AB</td></tr>
+            <tr><td>C</td><td>D</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.transpose().to_rst(), concat!(
+            "+----+---+\n",
+            "| AB | C |\n",
+            "|    +---+\n",
+            "|    | D |\n",
+            "+----+---+\n",
+        ));
+    }
+
+    // 한글(CJK) 문자는 2칸 폭으로 계산되어야 함
+    #[test]
+    fn test_wide_characters() {
+        let table = Table::from_html(r#"<table>
+            <tr><td>이름</td><td>a</td></tr>
+            <tr><td>홍길동</td><td>b</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.to_rst(), concat!(
+            "+--------+---+\n",
+            "| 이름   | a |\n",
+            "+--------+---+\n",
+            "| 홍길동 | b |\n",
+            "+--------+---+\n",
+        ));
+    }
+
+    // 빈 테이블은 빈 문자열
+    #[test]
+    fn test_empty_table() {
+        let table = Table::from_html("<table></table>").unwrap();
+        assert_eq!(table.to_rst(), "");
+    }
+}
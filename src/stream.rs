@@ -0,0 +1,290 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Read;
+
+use html5ever::tendril::StrTendril;
+use html5ever::tokenizer::states::RawKind;
+use html5ever::tokenizer::{
+    BufferQueue, CharacterTokens, EndTag, NullCharacterToken, StartTag, Tag, TagToken, Token, TokenSink,
+    TokenSinkResult, Tokenizer, TokenizerOpts,
+};
+
+use crate::table::{MAX_COLSPAN, MAX_ROWSPAN, Table, TableBuilder, span_value};
+
+// 한 번에 읽는 입력 크기
+const CHUNK_SIZE: usize = 64 * 1024;
+
+impl Table {
+    // DOM을 만들지 않고 토큰을 읽으면서 첫 번째 <table>을 행 단위로 구성한다
+    // 테이블이 끝나면 나머지 입력은 읽지 않으므로, 매우 큰 문서도 테이블 크기만큼의 메모리로 읽을 수 있다
+    pub fn from_html_reader(mut reader: impl Read) -> Result<Table, String> {
+        let tokenizer = Tokenizer::new(TableSink::default(), TokenizerOpts::default());
+        let queue = BufferQueue::default();
+        let mut buffer = vec![0; CHUNK_SIZE];
+        let mut pending: Vec<u8> = Vec::new();
+
+        while !tokenizer.sink.state.borrow().finished {
+            let read = reader.read(&mut buffer).map_err(|e| format!("Failed to read HTML input: {}", e))?;
+            if read == 0 {
+                break;
+            }
+            pending.extend_from_slice(&buffer[..read]);
+            let text = take_utf8(&mut pending, false);
+            queue.push_back(StrTendril::from(text));
+            let _ = tokenizer.feed(&queue);
+        }
+        if !tokenizer.sink.state.borrow().finished {
+            let text = take_utf8(&mut pending, true);
+            if !text.is_empty() {
+                queue.push_back(StrTendril::from(text));
+                let _ = tokenizer.feed(&queue);
+            }
+            tokenizer.end();
+        }
+
+        tokenizer.sink.state.into_inner().finish()
+    }
+}
+
+// 읽은 바이트에서 완성된 UTF-8 부분을 꺼낸다. 끝에서 잘린 문자는 다음 조각과 합치도록 남긴다
+fn take_utf8(pending: &mut Vec<u8>, at_end: bool) -> String {
+    let valid = match std::str::from_utf8(pending) {
+        Ok(_) => pending.len(),
+        Err(error) if error.error_len().is_none() && !at_end => error.valid_up_to(),
+        Err(_) => pending.len(),
+    };
+    let rest = pending.split_off(valid);
+    let text = String::from_utf8_lossy(pending).into_owned();
+    *pending = rest;
+    text
+}
+
+#[derive(Default)]
+struct TableSink {
+    state: RefCell<StreamState>,
+}
+
+// 진행 중인 셀
+struct PendingCell {
+    content: String,
+    rowspan: usize,
+    colspan: usize,
+    is_header: bool,
+    attributes: HashMap<String, String>,
+}
+
+#[derive(Default)]
+struct StreamState {
+    builder: TableBuilder,
+    table_attributes: Option<HashMap<String, String>>,
+    // 첫 번째 테이블 안에서 열린 <table> 깊이 (1이면 첫 번째 테이블 자체)
+    depth: usize,
+    in_row: bool,
+    in_caption: bool,
+    cell: Option<PendingCell>,
+    // 셀을 배치하다 생긴 첫 오류. 이후 입력은 읽지 않는다
+    error: Option<String>,
+    finished: bool,
+}
+
+impl StreamState {
+    fn start_tag(&mut self, tag: &Tag) {
+        let name = tag.name.as_ref();
+        if name == "table" {
+            if self.depth == 0 {
+                self.table_attributes = Some(attributes(tag, false));
+            }
+            self.depth += 1;
+            return;
+        }
+        if self.depth != 1 {
+            return;
+        }
+
+        match name {
+            "tr" => {
+                self.finish_row();
+                self.start_row();
+            }
+            "td" | "th" => {
+                self.finish_cell();
+                if !self.in_row {
+                    self.start_row();
+                }
+                self.cell = Some(PendingCell {
+                    content: String::new(),
+                    rowspan: span_value(attribute(tag, "rowspan"), MAX_ROWSPAN),
+                    colspan: span_value(attribute(tag, "colspan"), MAX_COLSPAN),
+                    is_header: name == "th",
+                    attributes: attributes(tag, true),
+                });
+            }
+            "thead" | "tbody" | "tfoot" => self.finish_row(),
+            "caption" => {
+                self.finish_row();
+                self.in_caption = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, tag: &Tag) {
+        if self.depth == 0 {
+            return;
+        }
+        let name = tag.name.as_ref();
+        if name == "table" {
+            self.depth -= 1;
+            if self.depth == 0 {
+                self.finish_row();
+                self.finished = true;
+            }
+            return;
+        }
+        if self.depth != 1 {
+            return;
+        }
+
+        match name {
+            "td" | "th" => self.finish_cell(),
+            "tr" | "thead" | "tbody" | "tfoot" => self.finish_row(),
+            "caption" => self.in_caption = false,
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_caption {
+            return;
+        }
+        if let Some(cell) = &mut self.cell {
+            cell.content.push_str(text);
+        }
+    }
+
+    fn start_row(&mut self) {
+        self.builder.start_row();
+        self.in_row = true;
+    }
+
+    fn finish_cell(&mut self) {
+        if let Some(cell) = self.cell.take() {
+            let content = cell.content.trim().to_string();
+            if let Err(error) = self.builder.add_cell(content, cell.rowspan, cell.colspan, cell.is_header, cell.attributes) {
+                self.error = Some(error);
+                self.finished = true;
+            }
+        }
+    }
+
+    fn finish_row(&mut self) {
+        self.finish_cell();
+        self.in_row = false;
+    }
+
+    fn finish(mut self) -> Result<Table, String> {
+        self.finish_row();
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let attributes = self.table_attributes.ok_or("No <table> element found")?;
+        self.builder.finish(attributes)
+    }
+}
+
+impl TokenSink for TableSink {
+    type Handle = ();
+
+    fn process_token(&self, token: Token, _line_number: u64) -> TokenSinkResult<()> {
+        let mut state = self.state.borrow_mut();
+        if state.finished {
+            return TokenSinkResult::Continue;
+        }
+        match token {
+            TagToken(tag) if tag.kind == StartTag => {
+                state.start_tag(&tag);
+                // 트리 구성기 없이 토큰화하므로 내용을 태그로 해석하지 않는 요소는 직접 알려 줘야 한다
+                match tag.name.as_ref() {
+                    "script" => return TokenSinkResult::RawData(RawKind::ScriptData),
+                    "style" | "xmp" | "iframe" | "noembed" | "noframes" => {
+                        return TokenSinkResult::RawData(RawKind::Rawtext);
+                    }
+                    "textarea" | "title" => return TokenSinkResult::RawData(RawKind::Rcdata),
+                    "plaintext" => return TokenSinkResult::Plaintext,
+                    _ => {}
+                }
+            }
+            TagToken(tag) if tag.kind == EndTag => state.end_tag(&tag),
+            CharacterTokens(text) => state.text(&text),
+            NullCharacterToken => state.text("\u{FFFD}"),
+            _ => {}
+        }
+        TokenSinkResult::Continue
+    }
+}
+
+fn attribute<'a>(tag: &'a Tag, name: &str) -> Option<&'a str> {
+    tag.attrs
+        .iter()
+        .find(|attr| attr.name.local.as_ref() == name)
+        .map(|attr| attr.value.as_ref())
+}
+
+// 태그의 속성 목록. 셀이면 rowspan/colspan은 병합 정보로 따로 관리하므로 뺀다
+fn attributes(tag: &Tag, is_cell: bool) -> HashMap<String, String> {
+    tag.attrs
+        .iter()
+        .filter(|attr| !is_cell || !matches!(attr.name.local.as_ref(), "rowspan" | "colspan"))
+        .map(|attr| (attr.name.local.to_string(), attr.value.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Table;
+
+    // 작은 조각으로 읽는 reader (청크 경계에서 UTF-8 문자와 태그가 잘리는 경우)
+    struct Trickle<'a>(&'a [u8]);
+
+    impl std::io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.0.len().min(buf.len()).min(3);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    const HTML: &str = r#"<html><head><title><table></title><script>if (a < b) "<td>";</script></head><body>
+        <table class="t"><caption>제목</caption>
+            <thead><tr><th colspan="2" id="h">헤더 &amp; 값</th></tr></thead>
+            <tbody>
+                <tr><td rowspan="2">A</td><td class="x">B<table><tr><td>안</td></tr></table></td></tr>
+                <tr><td>C
+            </tbody>
+        </table>
+        <table><tr><td>두 번째</td></tr></table>"#;
+
+    // 스트리밍 파서는 DOM 파서와 같은 테이블을 만들어야 함
+    #[test]
+    fn test_reader_matches_dom_parser() {
+        let streamed = Table::from_html_reader(HTML.as_bytes()).unwrap();
+        let parsed = Table::from_html(HTML).unwrap();
+        assert_eq!(streamed.to_html(), parsed.to_html());
+        assert_eq!(streamed.cell(0, 0).unwrap().content, "헤더 & 값");
+        assert_eq!(streamed.cell(1, 1).unwrap().content, "B안");
+
+        let trickled = Table::from_html_reader(Trickle(HTML.as_bytes())).unwrap();
+        assert_eq!(trickled.to_html(), parsed.to_html());
+    }
+
+    // 닫는 태그가 없는 셀과 행, 테이블이 없는 입력
+    #[test]
+    fn test_reader_implicit_tags() {
+        let html = "<table><td>1<td>2<tr><th>3</table>";
+        let streamed = Table::from_html_reader(html.as_bytes()).unwrap();
+        assert_eq!(streamed.to_html(), Table::from_html(html).unwrap().to_html());
+
+        assert!(Table::from_html_reader("<p>없음</p>".as_bytes()).is_err());
+    }
+}
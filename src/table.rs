@@ -0,0 +1,605 @@
+use std::collections::HashMap;
+use std::num::IntErrorKind;
+use std::sync::Arc;
+
+use crate::format::Format;
+use crate::{csv, html, markdown, mediawiki, org};
+
+// 셀이 없는 위치
+pub(crate) const EMPTY: u32 = u32::MAX;
+
+// 셀 하나의 데이터. 병합된 셀도 하나만 두고, 차지하는 위치들은 slots에서 이 셀을 가리킨다
+// 내용과 속성은 공유하므로 전치나 회전으로 만든 테이블이 문자열을 복사하지 않는다
+// 큰 테이블에서는 셀 수만큼 쌓이므로 위치와 크기는 u32로 줄여 둔다
+#[derive(Debug, Clone)]
+pub(crate) struct CellData {
+    pub(crate) row: u32,
+    pub(crate) col: u32,
+    pub(crate) rowspan: u32,
+    pub(crate) colspan: u32,
+    pub(crate) content: Arc<str>,
+    pub(crate) attributes: Option<Arc<HashMap<String, String>>>, // rowspan, colspan을 제외한 다른 속성들
+    pub(crate) is_header: bool, // 헤더 셀(th)인지 여부
+}
+
+// 전치할 때 함께 적용할 처리
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransposeOptions {
+    // 헤더 셀의 scope 값을 축에 맞게 바꾼다 (col <-> row, colgroup <-> rowgroup)
+    pub swap_scope: bool,
+    // scope가 없는 헤더 셀에 위치로 추론한 scope를 추가한다
+    pub infer_scope: bool,
+    // 셀 id와 headers 참조 앞에 붙일 접두사 (한 페이지에 여러 테이블을 넣을 때 id 충돌 방지)
+    pub id_prefix: Option<String>,
+    // headers가 없는 데이터 셀에 전치된 위치의 헤더 셀 id 목록을 추가한다
+    pub generate_headers: bool,
+    // 셀의 width/height, align/valign 속성과 인라인 스타일의 방향 관련 속성을 반대 축으로 바꾼다
+    pub swap_axis_styles: bool,
+}
+
+impl Default for TransposeOptions {
+    fn default() -> Self {
+        TransposeOptions {
+            swap_scope: true,
+            infer_scope: false,
+            id_prefix: None,
+            generate_headers: false,
+            swap_axis_styles: false,
+        }
+    }
+}
+
+// 테이블 모델: 행 우선 순서의 위치마다 그 위치를 차지하는 셀 번호를 두고, 셀 데이터는 따로 모아 둔다
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub(crate) attributes: HashMap<String, String>,
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) slots: Vec<u32>,
+    pub(crate) cells: Vec<CellData>,
+}
+
+// 셀 하나를 읽기 전용으로 보여주는 뷰 (병합된 셀은 시작 위치 기준)
+#[derive(Debug, Clone, Copy)]
+pub struct Cell<'a> {
+    pub row: usize,
+    pub col: usize,
+    pub rowspan: usize,
+    pub colspan: usize,
+    pub content: &'a str,
+    pub is_header: bool,
+    attributes: Option<&'a HashMap<String, String>>,
+}
+
+impl<'a> Cell<'a> {
+    pub fn attr(&self, name: &str) -> Option<&'a str> {
+        self.attributes.and_then(|attrs| attrs.get(name)).map(String::as_str)
+    }
+
+    pub fn attributes(&self) -> impl Iterator<Item = (&'a str, &'a str)> + use<'a> {
+        self.attributes
+            .into_iter()
+            .flat_map(|attrs| attrs.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+    }
+}
+
+impl Table {
+    pub fn from_html(html: &str) -> Result<Table, String> {
+        html::parse(html, None)
+    }
+
+    pub fn from_html_with_selector(html: &str, selector: &str) -> Result<Table, String> {
+        html::parse_with_selector(html, selector, None)
+    }
+
+    // 이미 파싱한 scraper 문서의 <table> 요소를 읽는다
+    // 벤치마크에서 문서 파싱과 그리드 구성을 따로 재기 위한 것으로, scraper 타입이 공개 API에 드러나지 않도록 bench 기능에서만 내보낸다
+    #[cfg(any(test, feature = "bench"))]
+    #[doc(hidden)]
+    pub fn from_html_element(element: scraper::ElementRef<'_>) -> Result<Table, String> {
+        if element.value().name() != "table" {
+            return Err(format!("Expected a <table> element, found <{}>", element.value().name()));
+        }
+        html::read_table(element, None)
+    }
+
+    pub fn from_mediawiki(text: &str) -> Result<Table, String> {
+        mediawiki::parse(text, None)
+    }
+
+    pub fn from_org(text: &str) -> Result<Table, String> {
+        org::parse(text, None)
+    }
+
+    pub fn from_csv(text: &str) -> Result<Table, String> {
+        csv::parse(text, None)
+    }
+
+    pub fn from_markdown(text: &str) -> Result<Table, String> {
+        markdown::parse(text, None)
+    }
+
+    pub fn to_html(&self) -> String {
+        self.render(Format::Html)
+    }
+
+    pub fn to_rst(&self) -> String {
+        self.render(Format::Rst)
+    }
+
+    pub fn to_asciidoc(&self) -> String {
+        self.render(Format::AsciiDoc)
+    }
+
+    pub fn to_mediawiki(&self) -> String {
+        self.render(Format::MediaWiki)
+    }
+
+    pub fn to_org(&self) -> String {
+        self.render(Format::Org)
+    }
+
+    pub fn to_csv(&self) -> String {
+        self.render(Format::Csv)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        self.render(Format::Markdown)
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.cols
+    }
+
+    pub fn table_attributes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.attributes.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    // (row, col)에서 시작하는 셀. 병합으로 가려진 위치나 빈 위치는 None
+    pub fn cell(&self, row: usize, col: usize) -> Option<Cell<'_>> {
+        let data = self.owner(row, col)?;
+        (data.origin() == (row, col)).then(|| data.view())
+    }
+
+    // 행 우선 순서로 모든 셀
+    pub fn cells(&self) -> Vec<Cell<'_>> {
+        let mut cells = Vec::new();
+        for (position, &slot) in self.slots.iter().enumerate() {
+            if let Some(data) = self.cells.get(slot as usize)
+                && data.origin() == (position / self.cols, position % self.cols)
+            {
+                cells.push(data.view());
+            }
+        }
+        cells
+    }
+
+    // 위치를 차지하는 셀
+    fn owner(&self, row: usize, col: usize) -> Option<&CellData> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.cells.get(self.slots[row * self.cols + col] as usize)
+    }
+
+    // 병합된 셀에 가려진 위치인지 (셀이 시작하는 위치나 빈 위치가 아님)
+    pub(crate) fn is_covered(&self, row: usize, col: usize) -> bool {
+        self.owner(row, col).is_some_and(|data| data.origin() != (row, col))
+    }
+
+    // 각 위치를 차지하는 셀의 번호. cells()의 인덱스를 쓰고, 셀이 없는 위치는 그 뒤의 고유 번호를 받는다
+    pub(crate) fn owners(&self, cells: &[Cell<'_>]) -> Vec<Vec<usize>> {
+        let mut owners = vec![vec![usize::MAX; self.col_count()]; self.row_count()];
+        for (idx, cell) in cells.iter().enumerate() {
+            for row in owners.iter_mut().skip(cell.row).take(cell.rowspan) {
+                for owner in row.iter_mut().skip(cell.col).take(cell.colspan) {
+                    *owner = idx;
+                }
+            }
+        }
+        let mut next = cells.len();
+        for owner in owners.iter_mut().flatten() {
+            if *owner == usize::MAX {
+                *owner = next;
+                next += 1;
+            }
+        }
+        owners
+    }
+
+    pub fn transpose(&self) -> Table {
+        self.transpose_with(&TransposeOptions::default())
+    }
+
+    pub fn transpose_with(&self, options: &TransposeOptions) -> Table {
+        let mut transposed = self.transpose_grid();
+        if options.swap_scope {
+            transposed.swap_scope();
+        }
+        if options.infer_scope {
+            transposed.infer_scope();
+        }
+        if options.swap_axis_styles {
+            transposed.swap_axis_styles();
+        }
+        let prefix = options.id_prefix.as_deref().unwrap_or("");
+        if !prefix.is_empty() {
+            transposed.prefix_ids(prefix);
+        }
+        if options.generate_headers {
+            transposed.generate_headers(prefix);
+        }
+        transposed
+    }
+
+    // 위치에서 시작하는 셀의 속성 목록 (공유 중이면 이 셀의 것만 복사해 고친다)
+    pub(crate) fn cell_attributes_mut(&mut self, (row, col): (usize, usize)) -> &mut HashMap<String, String> {
+        let index = self.slots[row * self.cols + col] as usize;
+        Arc::make_mut(self.cells[index].attributes.get_or_insert_default())
+    }
+
+    fn transpose_grid(&self) -> Table {
+        self.remap(self.cols, self.rows, true, |row, col| (col, row))
+    }
+
+    // 위치를 옮긴 rows x cols 크기의 새 테이블. position은 기존 위치를 새 위치로 바꾸는 일대일 대응이다
+    // 셀 데이터는 공유하고 시작 위치와 (swap_spans이면 서로 바꾼) 병합 크기만 다시 계산한다
+    pub(crate) fn remap(
+        &self,
+        rows: usize,
+        cols: usize,
+        swap_spans: bool,
+        position: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Table {
+        let mut slots = vec![EMPTY; rows * cols];
+        for (index, &slot) in self.slots.iter().enumerate() {
+            let (row, col) = position(index / self.cols, index % self.cols);
+            slots[row * cols + col] = slot;
+        }
+
+        let cells = self
+            .cells
+            .iter()
+            .map(|data| {
+                // 옮긴 영역의 왼쪽 위 모서리가 새 시작 위치
+                let (row, col) = data.origin();
+                let (rowspan, colspan) = data.spans();
+                let first = position(row, col);
+                let last = position(row + rowspan - 1, col + colspan - 1);
+                let (rowspan, colspan) = if swap_spans {
+                    (data.colspan, data.rowspan)
+                } else {
+                    (data.rowspan, data.colspan)
+                };
+                CellData {
+                    row: first.0.min(last.0) as u32,
+                    col: first.1.min(last.1) as u32,
+                    rowspan,
+                    colspan,
+                    ..data.clone()
+                }
+            })
+            .collect();
+
+        Table {
+            attributes: self.attributes.clone(),
+            rows,
+            cols,
+            slots,
+            cells,
+        }
+    }
+}
+
+impl CellData {
+    pub(crate) fn origin(&self) -> (usize, usize) {
+        (self.row as usize, self.col as usize)
+    }
+
+    pub(crate) fn spans(&self) -> (usize, usize) {
+        (self.rowspan as usize, self.colspan as usize)
+    }
+
+    fn view(&self) -> Cell<'_> {
+        let (row, col) = self.origin();
+        let (rowspan, colspan) = self.spans();
+        Cell {
+            row,
+            col,
+            rowspan,
+            colspan,
+            content: &self.content,
+            is_header: self.is_header,
+            attributes: self.attributes.as_deref(),
+        }
+    }
+}
+
+// 입력 포맷 파서가 공유하는 그리드 구성기
+// 행을 시작하고 셀을 차례로 넣으면 rowspan/colspan으로 가려진 위치를 건너뛰며 배치한다
+#[derive(Debug, Default)]
+pub(crate) struct TableBuilder {
+    // 행마다 위치별 셀 번호 (행 길이는 finish에서 맞춘다)
+    slots: Vec<Vec<u32>>,
+    // 가장 긴 행의 길이
+    cols: usize,
+    cells: Vec<CellData>,
+    row_idx: Option<usize>,
+    col_idx: usize,
+    // 그리드 위치 수 상한 (None이면 제한 없음). 넘으면 할당하지 않고 오류
+    max_cells: Option<usize>,
+}
+
+impl TableBuilder {
+    pub(crate) fn with_limit(max_cells: Option<usize>) -> TableBuilder {
+        TableBuilder { max_cells, ..TableBuilder::default() }
+    }
+
+    pub(crate) fn start_row(&mut self) {
+        let row_idx = self.row_idx.map_or(0, |r| r + 1);
+        self.row_idx = Some(row_idx);
+        if row_idx >= self.slots.len() {
+            self.slots.push(Vec::new());
+        }
+
+        self.col_idx = 0;
+        self.skip_filled();
+    }
+
+    pub(crate) fn add_cell(
+        &mut self,
+        content: String,
+        rowspan: usize,
+        colspan: usize,
+        is_header: bool,
+        attributes: HashMap<String, String>,
+    ) -> Result<(), String> {
+        if self.row_idx.is_none() {
+            self.start_row();
+        }
+        let row_idx = self.row_idx.unwrap_or(0);
+        self.skip_filled();
+
+        let colspan = self.place_cell((row_idx, self.col_idx), content, rowspan, colspan, is_header, attributes)?;
+
+        self.col_idx += colspan;
+        Ok(())
+    }
+
+    // 위치를 지정해 셀을 배치
+    // 이미 다른 셀이 차지한 위치와 겹치지 않도록 병합 크기를 줄이고, 실제로 차지한 열 수를 돌려준다
+    fn place_cell(
+        &mut self,
+        (row_idx, col_idx): (usize, usize),
+        content: String,
+        rowspan: usize,
+        colspan: usize,
+        is_header: bool,
+        attributes: HashMap<String, String>,
+    ) -> Result<usize, String> {
+        let to_u32 = |n: usize| u32::try_from(n).ok().filter(|&n| n != EMPTY).ok_or_else(too_large_error);
+
+        let is_free = |row: usize, col: usize| {
+            self.slots.get(row).and_then(|slots| slots.get(col)).is_none_or(|&slot| slot == EMPTY)
+        };
+        let colspan = (1..colspan.max(1)).take_while(|&c| is_free(row_idx, col_idx + c)).count() + 1;
+        // 아직 없는 행은 비어 있으므로 이미 있는 행만 확인한다
+        let rowspan = rowspan.max(1);
+        let rowspan = (1..rowspan)
+            .take_while(|&r| row_idx + r < self.slots.len())
+            .find(|&r| !(col_idx..col_idx + colspan).all(|col| is_free(row_idx + r, col)))
+            .unwrap_or(rowspan);
+
+        let needed_rows = row_idx.checked_add(rowspan).ok_or_else(too_large_error)?;
+        let needed_cols = col_idx.checked_add(colspan).ok_or_else(too_large_error)?;
+        let cell = CellData {
+            row: to_u32(row_idx)?,
+            col: to_u32(col_idx)?,
+            rowspan: to_u32(rowspan)?,
+            colspan: to_u32(colspan)?,
+            content: content.into(),
+            attributes: (!attributes.is_empty()).then(|| Arc::new(attributes)),
+            is_header,
+        };
+        let index = to_u32(self.cells.len())?;
+        self.check_size(self.slots.len().max(needed_rows), self.cols.max(needed_cols))?;
+
+        while self.slots.len() < needed_rows {
+            self.slots.push(Vec::new());
+        }
+        // 셀이 걸치는 행만 넓힌다. 나머지 행은 finish에서 맞춘다
+        for row in &mut self.slots[row_idx..needed_rows] {
+            if row.len() < needed_cols {
+                row.resize(needed_cols, EMPTY);
+            }
+            row[col_idx..needed_cols].fill(index);
+        }
+        self.cols = self.cols.max(needed_cols);
+
+        self.cells.push(cell);
+        Ok(colspan)
+    }
+
+    pub(crate) fn finish(self, attributes: HashMap<String, String>) -> Result<Table, String> {
+        // 행마다 셀 수가 다를 수 있으므로 가장 긴 행에 맞춰 빈 칸을 채운다
+        let rows = self.slots.len();
+        let cols = self.cols;
+        self.check_size(rows, cols)?;
+        let mut slots = Vec::with_capacity(rows * cols);
+        for mut row in self.slots {
+            row.resize(cols, EMPTY);
+            slots.extend(row);
+        }
+
+        Ok(Table {
+            attributes,
+            rows,
+            cols,
+            slots,
+            cells: self.cells,
+        })
+    }
+
+    fn check_size(&self, rows: usize, cols: usize) -> Result<(), String> {
+        let size = rows.checked_mul(cols).ok_or_else(too_large_error)?;
+        match self.max_cells {
+            Some(max_cells) if size > max_cells => Err(format!("Table is larger than {} cells", max_cells)),
+            _ => Ok(()),
+        }
+    }
+
+    fn skip_filled(&mut self) {
+        let Some(row) = self.row_idx.and_then(|r| self.slots.get(r)) else {
+            return;
+        };
+        while self.col_idx < row.len() && row[self.col_idx] != EMPTY {
+            self.col_idx += 1;
+        }
+    }
+}
+
+fn too_large_error() -> String {
+    "Table is too large".to_string()
+}
+
+// HTML 표준의 rowspan/colspan 상한
+pub(crate) const MAX_ROWSPAN: usize = 65534;
+pub(crate) const MAX_COLSPAN: usize = 1000;
+
+// rowspan/colspan 속성 값. 0 이하나 숫자가 아닌 값은 1로, 상한보다 큰 값은 상한으로 취급
+pub(crate) fn span_value(value: Option<&str>, max: usize) -> usize {
+    let span = match value.map(|s| s.trim().parse::<usize>()) {
+        Some(Ok(n)) => n,
+        // usize로도 담을 수 없을 만큼 큰 값
+        Some(Err(e)) if *e.kind() == IntErrorKind::PosOverflow => max,
+        _ => 1,
+    };
+    span.clamp(1, max)
+}
+
+// 맨 위에서부터 모든 셀이 헤더(th)인 행 수. 병합 셀이 헤더 경계를 넘으면 그 위까지만 인정한다
+pub(crate) fn header_row_count(cells: &[Cell<'_>], owners: &[Vec<usize>]) -> usize {
+    let is_header = |owner: usize| cells.get(owner).is_some_and(|cell| cell.is_header);
+
+    let mut count = owners
+        .iter()
+        .take_while(|row| row.iter().all(|&owner| is_header(owner)))
+        .count();
+    if count == owners.len() {
+        return 0;
+    }
+    while count > 0 && owners[count - 1].iter().zip(&owners[count]).any(|(above, below)| above == below) {
+        count -= 1;
+    }
+    count
+}
+
+// 셀 내용의 공백을 한 칸으로 정리 (텍스트 기반 포맷은 셀을 한 줄로 출력)
+pub(crate) fn single_line(content: &str) -> String {
+    content.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 병합 셀이 전치 후 rowspan/colspan이 뒤바뀌는지 테스트
+    #[test]
+    fn test_transpose_swaps_spans() {
+        let table = Table::from_html(r#"<table>
+            <tr><td rowspan="2" colspan="3">A</td><td>B</td></tr>
+            <tr><td>C</td></tr>
+        </table>"#).unwrap();
+        let transposed = table.transpose();
+        assert_eq!(transposed.row_count(), 4);
+        assert_eq!(transposed.col_count(), 2);
+        let cell = transposed.cell(0, 0).unwrap();
+        assert_eq!((cell.rowspan, cell.colspan), (3, 2));
+        assert_eq!(transposed.cell(3, 1).unwrap().content, "C");
+        assert!(transposed.cell(1, 1).is_none());
+    }
+
+    // 전치와 회전은 셀 내용을 복사하지 않고 공유해야 함
+    #[test]
+    fn test_transpose_shares_cells() {
+        let table = Table::from_html("<table><tr><td>A</td><td>B</td></tr></table>").unwrap();
+        let transposed = table.transpose();
+        assert!(Arc::ptr_eq(&table.cells[0].content, &transposed.cells[0].content));
+        assert_eq!(transposed.slots, vec![0, 1]);
+        assert_eq!((transposed.cells[1].row, transposed.cells[1].col), (1, 0));
+    }
+
+    // 셀 목록과 위치별 소유 셀이 일치하는지 테스트
+    #[test]
+    fn test_cells_and_owners() {
+        let table = Table::from_html(r#"<table>
+            <tr><th colspan="2">H</th></tr>
+            <tr><td class="x">A</td><td>B</td></tr>
+        </table>"#).unwrap();
+        let cells = table.cells();
+        assert_eq!(cells.len(), 3);
+        assert!(cells[0].is_header);
+        assert_eq!(cells[1].attr("class"), Some("x"));
+        assert_eq!(table.owners(&cells), vec![vec![0, 0], vec![1, 2]]);
+    }
+
+    // 선택자로 문서 안의 특정 테이블을 고르는지 테스트
+    #[test]
+    fn test_from_html_with_selector() {
+        let html = r#"<table><tr><td>first</td></tr></table>
+            <div id="data"><table><tr><td>second</td></tr></table></div>
+            <table class="x"><tr><td>third</td></tr></table>"#;
+        let content = |selector| Table::from_html_with_selector(html, selector).unwrap().cell(0, 0).unwrap().content.to_string();
+        assert_eq!(content("#data"), "second");
+        assert_eq!(content("table.x"), "third");
+        assert!(Table::from_html_with_selector(html, "ul").is_err());
+        assert!(Table::from_html_with_selector(html, "[[").unwrap_err().starts_with("Invalid selector"));
+    }
+
+    // 파싱된 문서의 요소에서 읽기 (테이블이 아닌 요소는 오류)
+    #[test]
+    fn test_from_html_element() {
+        let document = scraper::Html::parse_document(r#"<div><table><tr><td>A</td></tr></table></div>"#);
+        let select = |selector| document.select(&scraper::Selector::parse(selector).unwrap()).next().unwrap();
+        assert_eq!(Table::from_html_element(select("table")).unwrap().cell(0, 0).unwrap().content, "A");
+        assert!(Table::from_html_element(select("div")).is_err());
+    }
+
+    // 병합 크기는 HTML 상한으로 자르고, usize보다 큰 값도 패닉 없이 읽는다
+    #[test]
+    fn test_span_limits() {
+        assert_eq!(span_value(Some(" 3 "), MAX_COLSPAN), 3);
+        assert_eq!(span_value(Some("0"), MAX_COLSPAN), 1);
+        assert_eq!(span_value(Some("-2"), MAX_COLSPAN), 1);
+        assert_eq!(span_value(Some("4000000000"), MAX_COLSPAN), MAX_COLSPAN);
+        assert_eq!(span_value(Some("18446744073709551616"), MAX_ROWSPAN), MAX_ROWSPAN);
+
+        let table = Table::from_html(r#"<table><tr><td rowspan="18446744073709551615">A</td></tr></table>"#).unwrap();
+        assert_eq!(table.cell(0, 0).unwrap().rowspan, MAX_ROWSPAN);
+        let table = Table::from_html(r#"<table><tr><td colspan="4000000000">A</td></tr></table>"#).unwrap();
+        assert_eq!(table.col_count(), MAX_COLSPAN);
+
+        // 크기 제한을 주면 그리드가 그보다 커질 때 할당하지 않고 오류
+        let huge = r#"<table><tr><td rowspan="65534" colspan="1000">A</td></tr></table>"#;
+        let error = Table::parse_with_limit(huge, Format::Html, 1 << 20).unwrap_err();
+        assert_eq!(error, "Table is larger than 1048576 cells");
+        assert!(Table::parse_with_limit("a,b\n1,2\n", Format::Csv, 3).is_err());
+        assert_eq!(Table::parse_with_limit("a,b\n1,2\n", Format::Csv, 4).unwrap().row_count(), 2);
+    }
+
+    // 겹치는 셀은 이미 배치된 셀을 덮어쓰지 않고 병합 크기를 줄인다
+    #[test]
+    fn test_overlapping_cells() {
+        let table = Table::from_html(r#"<table>
+            <tr><td>A</td><td rowspan="2">B</td></tr>
+            <tr><td colspan="3">C</td></tr>
+        </table>"#).unwrap();
+        assert_eq!(table.cell(0, 1).unwrap().rowspan, 2);
+        assert_eq!(table.cell(1, 0).unwrap().colspan, 1);
+        assert_eq!(table.cells().len(), 3);
+    }
+}
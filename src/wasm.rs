@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{AutoMergeOptions, Format, Table, TransposeOptions};
+
+// JavaScript용 바인딩. 오류는 메시지를 담은 Error로 던진다
+// 내부 처리는 String 오류를 돌려주는 함수로 나눠 두어 wasm이 아닌 환경에서도 테스트할 수 있게 한다
+
+#[wasm_bindgen(js_name = transpose)]
+pub fn transpose_js(html: &str) -> Result<String, JsError> {
+    crate::transpose(html).map_err(|e| JsError::new(&e))
+}
+
+#[wasm_bindgen(js_name = transposeWithOptions)]
+pub fn transpose_with_options_js(html: &str, options: &JsTransposeOptions) -> Result<String, JsError> {
+    crate::transpose_with_options(html, &options.to_options()).map_err(|e| JsError::new(&e))
+}
+
+// 포맷 이름(html, markdown, csv 등 명령줄의 --from/--to 값)으로 테이블을 다른 포맷으로 바꾼다
+#[wasm_bindgen]
+pub fn convert(text: &str, from: &str, to: &str) -> Result<String, JsError> {
+    convert_text(text, from, to).map_err(|e| JsError::new(&e))
+}
+
+fn convert_text(text: &str, from: &str, to: &str) -> Result<String, String> {
+    let to: Format = to.parse()?;
+    Ok(Table::parse(text, from.parse()?)?.render(to))
+}
+
+#[wasm_bindgen(js_name = TransposeOptions)]
+pub struct JsTransposeOptions {
+    #[wasm_bindgen(js_name = swapScope)]
+    pub swap_scope: bool,
+    #[wasm_bindgen(js_name = inferScope)]
+    pub infer_scope: bool,
+    #[wasm_bindgen(getter_with_clone, js_name = idPrefix)]
+    pub id_prefix: Option<String>,
+    #[wasm_bindgen(js_name = generateHeaders)]
+    pub generate_headers: bool,
+    #[wasm_bindgen(js_name = swapAxisStyles)]
+    pub swap_axis_styles: bool,
+}
+
+#[wasm_bindgen(js_class = TransposeOptions)]
+impl JsTransposeOptions {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> JsTransposeOptions {
+        let options = TransposeOptions::default();
+        JsTransposeOptions {
+            swap_scope: options.swap_scope,
+            infer_scope: options.infer_scope,
+            id_prefix: options.id_prefix,
+            generate_headers: options.generate_headers,
+            swap_axis_styles: options.swap_axis_styles,
+        }
+    }
+}
+
+impl Default for JsTransposeOptions {
+    fn default() -> Self {
+        JsTransposeOptions::new()
+    }
+}
+
+impl JsTransposeOptions {
+    fn to_options(&self) -> TransposeOptions {
+        TransposeOptions {
+            swap_scope: self.swap_scope,
+            infer_scope: self.infer_scope,
+            id_prefix: self.id_prefix.clone(),
+            generate_headers: self.generate_headers,
+            swap_axis_styles: self.swap_axis_styles,
+        }
+    }
+}
+
+// 테이블 모델
+#[wasm_bindgen(js_name = Table)]
+pub struct JsTable {
+    table: Table,
+}
+
+#[wasm_bindgen(js_class = Table)]
+impl JsTable {
+    // format을 생략하면 html
+    pub fn parse(text: &str, format: Option<String>) -> Result<JsTable, JsError> {
+        parse_table(text, format.as_deref()).map_err(|e| JsError::new(&e))
+    }
+
+    #[wasm_bindgen(getter, js_name = rowCount)]
+    pub fn row_count(&self) -> usize {
+        self.table.row_count()
+    }
+
+    #[wasm_bindgen(getter, js_name = colCount)]
+    pub fn col_count(&self) -> usize {
+        self.table.col_count()
+    }
+
+    // (row, col)에서 시작하는 셀. 병합으로 가려진 위치나 빈 위치는 undefined
+    pub fn cell(&self, row: usize, col: usize) -> Option<JsCell> {
+        self.table.cell(row, col).map(JsCell::from)
+    }
+
+    // 행 우선 순서로 모든 셀
+    pub fn cells(&self) -> Vec<JsCell> {
+        self.table.cells().into_iter().map(JsCell::from).collect()
+    }
+
+    pub fn transpose(&self, options: Option<JsTransposeOptions>) -> JsTable {
+        let options = options.map(|options| options.to_options()).unwrap_or_default();
+        JsTable { table: self.table.transpose_with(&options) }
+    }
+
+    // cw, ccw, 180
+    pub fn rotate(&self, direction: &str) -> Result<JsTable, JsError> {
+        rotate_table(&self.table, direction).map_err(|e| JsError::new(&e))
+    }
+
+    // duplicate(기본값) 또는 empty
+    pub fn unmerge(&self, fill: Option<String>) -> Result<JsTable, JsError> {
+        unmerge_table(&self.table, fill.as_deref()).map_err(|e| JsError::new(&e))
+    }
+
+    #[wasm_bindgen(js_name = autoMerge)]
+    pub fn auto_merge(&self) -> JsTable {
+        JsTable { table: self.table.auto_merge(&AutoMergeOptions::default()) }
+    }
+
+    pub fn render(&self, format: &str) -> Result<String, JsError> {
+        let format: Format = format.parse().map_err(|e: String| JsError::new(&e))?;
+        Ok(self.table.render(format))
+    }
+
+    #[wasm_bindgen(js_name = toHtml)]
+    pub fn to_html(&self) -> String {
+        self.table.to_html()
+    }
+}
+
+fn parse_table(text: &str, format: Option<&str>) -> Result<JsTable, String> {
+    let format = format.map_or(Ok(Format::Html), str::parse)?;
+    Ok(JsTable { table: Table::parse(text, format)? })
+}
+
+fn rotate_table(table: &Table, direction: &str) -> Result<JsTable, String> {
+    Ok(JsTable { table: table.rotate(direction.parse()?) })
+}
+
+fn unmerge_table(table: &Table, fill: Option<&str>) -> Result<JsTable, String> {
+    Ok(JsTable { table: table.unmerge(fill.unwrap_or("duplicate").parse()?) })
+}
+
+// 셀 하나 (값 복사본)
+#[wasm_bindgen(js_name = Cell)]
+pub struct JsCell {
+    pub row: usize,
+    pub col: usize,
+    pub rowspan: usize,
+    pub colspan: usize,
+    #[wasm_bindgen(getter_with_clone)]
+    pub content: String,
+    #[wasm_bindgen(js_name = isHeader)]
+    pub is_header: bool,
+    attributes: HashMap<String, String>,
+}
+
+#[wasm_bindgen(js_class = Cell)]
+impl JsCell {
+    pub fn attr(&self, name: &str) -> Option<String> {
+        self.attributes.get(name).cloned()
+    }
+
+    #[wasm_bindgen(js_name = attributeNames)]
+    pub fn attribute_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.attributes.keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+impl From<crate::Cell<'_>> for JsCell {
+    fn from(cell: crate::Cell<'_>) -> JsCell {
+        JsCell {
+            row: cell.row,
+            col: cell.col,
+            rowspan: cell.rowspan,
+            colspan: cell.colspan,
+            content: cell.content.to_string(),
+            is_header: cell.is_header,
+            attributes: cell.attributes().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"<table><tr><th colspan="2" class="h">H</th></tr><tr><td>A</td><td>B</td></tr></table>"#;
+
+    // 내보낸 함수와 테이블 모델
+    // wasm-pack test --node에서는 wasm_bindgen_test로, 그 밖에서는 일반 테스트로 실행한다
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_bindings() {
+        assert_eq!(
+            transpose_js("<table><tr><td>1</td><td>2</td></tr></table>").unwrap(),
+            "<table><tr><td>1</td></tr><tr><td>2</td></tr></table>"
+        );
+
+        let table = parse_table(HTML, None).unwrap();
+        assert_eq!((table.row_count(), table.col_count()), (2, 2));
+        let header = table.cell(0, 0).unwrap();
+        assert!(header.is_header && header.colspan == 2);
+        assert_eq!(header.attr("class").as_deref(), Some("h"));
+        assert_eq!(header.attribute_names(), vec!["class"]);
+        assert!(table.cell(0, 1).is_none());
+
+        let transposed = table.transpose(None);
+        assert_eq!(transposed.cell(0, 0).unwrap().rowspan, 2);
+        assert_eq!(transposed.cells().len(), 3);
+        assert_eq!(unmerge_table(&table.table, Some("empty")).unwrap().cells().len(), 4);
+        assert_eq!(rotate_table(&table.table, "180").unwrap().cell(0, 0).unwrap().content, "B");
+    }
+
+    // 포맷 이름과 잘못된 인자는 오류 메시지로
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test::wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_convert_and_errors() {
+        assert_eq!(convert_text("| a | b |", "org", "html").unwrap(), "<table><tr><td>a</td><td>b</td></tr></table>");
+        assert!(convert_text("| a |", "org", "xlsx").unwrap_err().starts_with("Unknown format"));
+        assert!(convert_text("x", "rst", "html").is_err());
+
+        let table = parse_table(HTML, Some("html")).unwrap();
+        assert!(rotate_table(&table.table, "left").is_err());
+        assert!(unmerge_table(&table.table, Some("zero")).is_err());
+        assert!(parse_table("<p>x</p>", None).is_err());
+    }
+}